@@ -1,82 +1,123 @@
-use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use skip_linked_list::list::SkipLinkedList;
-use rand::prelude::*;
-use rand::distributions::WeightedIndex;
+use skip_linked_list::ops::{self, Op};
+use rand::{thread_rng, Rng};
 
-enum Instruction {
-    Insert(usize, i32),
-    Get(usize),
-    Remove(usize),
-}
-
-fn simulate_vec(instructions: &Vec<Instruction>) {
+fn simulate_vec(ops: &[Op<i32>]) {
     let mut vec = Vec::new();
 
-    for instruction in instructions {
-        match instruction {
-            Instruction::Insert(i, elem) => vec.insert(*i, elem),
-            Instruction::Get(i) => {
-                vec[*i];
+    for op in ops {
+        match op {
+            Op::Insert(i, elem) => vec.insert(*i, elem),
+            Op::Get(i) => {
+                black_box(vec[*i]);
             },
-            Instruction::Remove(i) => {
+            Op::Remove(i) => {
                 vec.remove(*i);
             }
         }
     }
 }
 
-fn simulate_skip_linked_list(instructions: &Vec<Instruction>) {
+fn simulate_skip_linked_list(ops: &[Op<i32>]) {
     let mut list = SkipLinkedList::new();
 
-    for instruction in instructions {
-        match instruction {
-            Instruction::Insert(i, elem) => {
-                list.insert(*i, elem);
-            },
-            Instruction::Get(i) => {
-                list.get(*i);
-            },
-            Instruction::Remove(i) => {
-                list.remove(*i);
-            },
+    for op in ops {
+        black_box(list.apply(op.clone()));
+    }
+}
+
+fn bench_writes_heavy(c: &mut Criterion) {
+    let sizes = [10000, 50000, 200000];
+    let mut group = c.benchmark_group("writes_heavy");
+    for n in sizes.iter() {
+        let instructions: Vec<Op<i32>> = ops::generate(&mut thread_rng(), *n, [70, 20, 10]);
+        group.bench_function(BenchmarkId::new("vec ", n), |b| b.iter(|| simulate_vec(&instructions)));
+        group.bench_function(BenchmarkId::new("skiplist ", n), |b| b.iter(|| simulate_skip_linked_list(&instructions)));
+    }
+}
+
+fn bench_sequential_get(c: &mut Criterion) {
+    let sizes = [10000, 50000, 200000];
+    let mut group = c.benchmark_group("sequential_get");
+    for n in sizes.iter() {
+        let mut list = SkipLinkedList::new();
+        for i in 0..*n {
+            list.push_back(i);
         }
+        group.bench_function(BenchmarkId::new("descent ", n), |b| b.iter(|| {
+            for i in 0..*n {
+                black_box(list.get(i));
+            }
+        }));
+        list.enable_finger_search(true);
+        group.bench_function(BenchmarkId::new("finger ", n), |b| b.iter(|| {
+            for i in 0..*n {
+                black_box(list.get(i));
+            }
+        }));
     }
 }
 
-fn generate_instructions(n: usize, weight_insert: usize, weight_get: usize, weight_remove: usize) -> Vec<Instruction> {
-    let mut instructions = Vec::new();
+fn bench_extend(c: &mut Criterion) {
+    let sizes = [10000, 50000, 200000];
+    let mut group = c.benchmark_group("extend");
+    for n in sizes.iter() {
+        group.bench_function(BenchmarkId::new("sized ", n), |b| b.iter(|| {
+            let mut list = SkipLinkedList::new();
+            list.extend(0..*n);
+            list
+        }));
+        group.bench_function(BenchmarkId::new("unsized ", n), |b| b.iter(|| {
+            let mut list = SkipLinkedList::new();
+            list.extend((0..*n).filter(|_| true));
+            list
+        }));
+    }
+}
 
-    let mut size = 0;
-    let weights = vec![weight_insert, weight_get, weight_remove];
-    let choices = vec!["i", "g", "r"];
-    let dist = WeightedIndex::new(&weights).unwrap();
-    let mut rng = thread_rng();
-    while instructions.len() < n {
-        match choices[dist.sample(&mut rng)] {
-            "i" => {
-                instructions.push(Instruction::Insert(rng.gen_range(0, size + 1), rng.gen()));
-                size += 1;
-            },
-            "g" if size > 0 => instructions.push(Instruction::Get(rng.gen_range(0, size))),
-            "r" if size > 0 => {
-                instructions.push(Instruction::Remove(rng.gen_range(0, size)));
-                size -= 1;
-            },
-            _ => (),
-        };
+fn bench_bulk_writes(c: &mut Criterion) {
+    let sizes = [10000, 50000, 200000];
+    let mut group = c.benchmark_group("bulk_writes");
+    for n in sizes.iter() {
+        group.bench_function(BenchmarkId::new("per_op ", n), |b| b.iter(|| {
+            let mut list: SkipLinkedList<usize> = (0..*n).collect();
+            for i in 0..*n {
+                list.insert(2 * i + 1, i);
+            }
+            list
+        }));
+        group.bench_function(BenchmarkId::new("bulk ", n), |b| b.iter(|| {
+            let mut list: SkipLinkedList<usize> = (0..*n).collect();
+            list.begin_bulk();
+            for i in 0..*n {
+                list.insert(2 * i + 1, i);
+            }
+            list.end_bulk();
+            list
+        }));
     }
-    instructions
 }
 
-fn bench_writes_heavy(c: &mut Criterion) {
+fn bench_frozen_get(c: &mut Criterion) {
     let sizes = [10000, 50000, 200000];
-    let mut group = c.benchmark_group("writes_heavy");
+    let mut group = c.benchmark_group("frozen_get");
     for n in sizes.iter() {
-        let instructions = generate_instructions(*n, 70, 20, 10);
-        group.bench_function(BenchmarkId::new("vec ", n), |b| b.iter(|| simulate_vec(&instructions)));
-        group.bench_function(BenchmarkId::new("skiplist ", n), |b| b.iter(|| simulate_skip_linked_list(&instructions)));
+        let indices: Vec<usize> = (0..*n).map(|_| thread_rng().gen_range(0, *n)).collect();
+        let list: SkipLinkedList<usize> = (0..*n).collect();
+        group.bench_function(BenchmarkId::new("list ", n), |b| b.iter(|| {
+            for i in indices.iter() {
+                black_box(list.get(*i));
+            }
+        }));
+        let frozen = list.freeze();
+        group.bench_function(BenchmarkId::new("frozen ", n), |b| b.iter(|| {
+            for i in indices.iter() {
+                black_box(frozen.get(*i));
+            }
+        }));
     }
 }
 
-criterion_group!(benches, bench_writes_heavy);
+criterion_group!(benches, bench_writes_heavy, bench_sequential_get, bench_extend, bench_bulk_writes, bench_frozen_get);
 criterion_main!(benches);
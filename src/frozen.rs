@@ -0,0 +1,119 @@
+//! An immutable, contiguous copy of a `SkipLinkedList` for read-heavy phases.
+
+use crate::list::SkipLinkedList;
+
+/// # FrozenList
+///
+/// `FrozenList` holds the elements of a `SkipLinkedList` in a flat vector, created by
+/// [`SkipLinkedList::freeze`]. It cannot be modified, but `get` takes `O(1)` time and `iter` walks
+/// contiguous memory. [`thaw`](Self::thaw) turns it back into a list with the original
+/// configuration.
+///
+/// # Examples
+/// ```
+/// let list: skip_linked_list::SkipLinkedList<_> = (0..5).collect();
+///
+/// let frozen = list.freeze();
+/// assert_eq!(frozen.get(3), Some(&3));
+/// assert_eq!(frozen.len(), 5);
+///
+/// let mut list = frozen.thaw();
+/// list.push_back(5);
+/// assert_eq!(list.len(), 6);
+/// ```
+pub struct FrozenList<T> {
+    elems: Vec<T>,
+    config: SkipLinkedList<T>,
+}
+
+impl<T> FrozenList<T> {
+    pub(crate) fn new(list: SkipLinkedList<T>) -> Self {
+        let config = list.clone_config();
+        Self { elems: list.into_vec(), config }
+    }
+
+    /// Rebuilds a list holding the elements, configured like the list that was frozen.
+    pub fn thaw(self) -> SkipLinkedList<T> {
+        let mut list = self.config;
+        list.extend(self.elems);
+        list
+    }
+
+    /// Returns the element at index `i`, or `None` if it's out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.elems.get(i)
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elems.is_empty()
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.elems.iter()
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.elems
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FrozenList<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn freeze() {
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for _ in 0..1000 {
+            let elem: i32 = thread_rng().gen();
+            let idx = thread_rng().gen_range(0, vec.len() + 1);
+            list.insert(idx, elem);
+            vec.insert(idx, elem);
+        }
+        let frozen = list.freeze();
+        assert_eq!(frozen.len(), 1000);
+        assert_eq!(frozen.as_slice(), &vec[..]);
+        for (i, elem) in vec.iter().enumerate() {
+            assert_eq!(frozen.get(i), Some(elem));
+        }
+        assert_eq!(frozen.get(1000), None);
+        assert!(frozen.iter().eq(vec.iter()));
+    }
+
+    #[test]
+    fn thaw() {
+        let mut list = SkipLinkedList::with_seed(8);
+        list.set_soft_cap(Some(3));
+        list.extend(0..3);
+        let mut other = SkipLinkedList::with_seed(8);
+        other.extend(0..3);
+
+        let mut list = list.freeze().thaw();
+        assert!(list.eq_iter(0..3));
+        assert_eq!(list.try_push_back(3), Err(3));
+        assert_eq!(list.render(), other.render());
+
+        let frozen = SkipLinkedList::<i32>::new().freeze();
+        assert!(frozen.is_empty());
+        assert_eq!(frozen.thaw().len(), 0);
+    }
+}
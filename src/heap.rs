@@ -0,0 +1,109 @@
+//! A priority queue backed by a `SkipLinkedList`.
+
+use crate::list::SkipLinkedList;
+
+/// # SkipHeap
+///
+/// `SkipHeap` is a min-heap that keeps its elements sorted in a `SkipLinkedList`.
+/// Both `push` and `pop` take `O(log n)` time, and the elements can be read back in sorted order.
+///
+/// # Examples
+/// ```
+/// let mut heap = skip_linked_list::SkipHeap::new();
+///
+/// heap.push(3);
+/// heap.push(1);
+/// heap.push(2);
+///
+/// assert_eq!(heap.peek(), Some(&1));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), Some(2));
+/// assert_eq!(heap.pop(), Some(3));
+/// assert_eq!(heap.pop(), None);
+/// ```
+pub struct SkipHeap<T: Ord> {
+    list: SkipLinkedList<T>,
+}
+
+impl<T: Ord> SkipHeap<T> {
+
+    /// Creates a new heap.
+    pub fn new() -> Self {
+        Self { list: SkipLinkedList::new() }
+    }
+
+    /// Pushes an element onto the heap.
+    pub fn push(&mut self, elem: T) {
+        self.list.insert_sorted(elem);
+    }
+
+    /// Removes the smallest element from the heap and returns it, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if !self.list.is_empty() {
+            Some(self.list.pop_front())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest element in the heap, or `None` if it is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the heap contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns an iterator over the elements in ascending order.
+    pub fn iter(&self) -> crate::list::Iter<'_, T> {
+        self.list.iter()
+    }
+}
+
+impl<T: Ord> Default for SkipHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{thread_rng, Rng};
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    #[test]
+    fn matches_binary_heap() {
+        let mut heap = SkipHeap::new();
+        let mut binary_heap = BinaryHeap::new();
+        for _ in 0..1000 {
+            let elem: i32 = thread_rng().gen_range(0, 500);
+            heap.push(elem);
+            binary_heap.push(Reverse(elem));
+        }
+        assert_eq!(heap.len(), 1000);
+        while let Some(Reverse(elem)) = binary_heap.pop() {
+            assert_eq!(heap.peek(), Some(&elem));
+            assert_eq!(heap.pop(), Some(elem));
+        }
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut heap = SkipHeap::new();
+        for elem in [5, 3, 8, 1].iter() {
+            heap.push(*elem);
+        }
+        assert_eq!(heap.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5, 8]);
+    }
+}
@@ -2,5 +2,12 @@
 //!
 //! A skiplist-backed linked list that support fast random writes.
 
+pub mod frozen;
+pub mod heap;
 pub mod list;
-pub use list::SkipLinkedList;
\ No newline at end of file
+pub mod ops;
+pub mod sum;
+pub use frozen::FrozenList;
+pub use heap::SkipHeap;
+pub use list::SkipLinkedList;
+pub use sum::SumList;
\ No newline at end of file
@@ -1,8 +1,22 @@
 extern crate rand;
 
-use rand::{thread_rng, Rng};
+use rand::{thread_rng, Rng, SeedableRng};
+use rand::rngs::StdRng;
+use crate::frozen::FrozenList;
+use crate::ops::{Edit, Op, OpResult};
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ops::{Add, Bound, Div, Range, RangeBounds, Sub};
 use std::ptr::NonNull;
-use std::fmt::Display;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+use std::fmt::{Display, Write};
+use std::io::{self, BufRead, BufReader, Read};
 
 /// # SkipLinkedList
 ///
@@ -28,551 +42,6659 @@ use std::fmt::Display;
 pub struct SkipLinkedList<T> {
     size: usize,
     entry: Link<T>,
+    tail: Option<WeakLink<T>>,
+    rng: RefCell<StdRng>,
+    seed: Option<u64>,
+    probability: f64,
+    comparator: Option<Rc<Comparator<T>>>,
+    capacity: Option<usize>,
+    soft_cap: Option<usize>,
+    finger_search: bool,
+    bulk: bool,
+    finger: Cell<Option<(usize, WeakLink<T>)>>,
+    #[cfg(feature = "metrics")]
+    metrics: Cell<Metrics>,
 }
 
+type Comparator<T> = dyn Fn(&T, &T) -> Ordering;
+
+/// How far `get` walks forward from the finger before falling back to a descent from the top.
+const FINGER_REACH: usize = 32;
+
 type Link<T> = Box<Node<T>>;
 type WeakLink<T> = NonNull<Node<T>>;
 
-enum Node<T> {
+/// Points at the node holding a handled element; the node owns it, and handles keep it weakly.
+type HandleSlot<T> = Rc<Cell<WeakLink<T>>>;
+
+/// A node on one level of the tower.
+///
+/// Every level is doubly linked: `prev` points at the node to the left, which is the level's
+/// sentinel for its first node. Since there always is such a node, `prev` is a plain `WeakLink`
+/// rather than an `Option`.
+pub(crate) enum Node<T> {
     Sentinel { right: Option<Link<T>>, down: Option<Link<T>>, delta: usize },
-    Index { right: Option<Link<T>>, down: WeakLink<T>, delta: usize },
-    Content { right: Option<Link<T>>, elem: T },
+    Index { right: Option<Link<T>>, prev: WeakLink<T>, down: WeakLink<T>, up: Option<WeakLink<T>>, delta: usize },
+    Content { right: Option<Link<T>>, prev: WeakLink<T>, up: Option<WeakLink<T>>, handle: Option<HandleSlot<T>>, elem: T },
+}
+
+/// Cumulative traversal counts of `insert`, `get` and `remove`, as returned by
+/// [`SkipLinkedList::operation_metrics`].
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Metrics {
+    /// Number of descents from the top of the tower.
+    pub total_descents: usize,
+    /// Number of links followed, counted as in [`SkipLinkedList::lookup_cost`].
+    pub total_node_visits: usize,
+    /// Number of operations.
+    pub op_count: usize,
+}
+
+/// The number of nodes of each kind in a list, as returned by [`SkipLinkedList::node_counts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NodeCounts {
+    /// Nodes holding an element, one per element.
+    pub content: usize,
+    /// Index nodes on the levels above the content level.
+    pub index: usize,
+    /// Sentinel nodes, one per level.
+    pub sentinel: usize,
+}
+
+/// Identifies an element of a list independently of its index, as returned by
+/// [`SkipLinkedList::push_front_handle`].
+///
+/// A handle holds a weak reference to a slot owned by the node of its element, so it goes stale
+/// as soon as the element is dropped, even if a newer node is later allocated at the same address.
+/// When the element moves to another node, as in `swap_adjacent`, the slot moves with it.
+#[derive(Debug)]
+pub struct Handle<T> {
+    token: Weak<Cell<WeakLink<T>>>,
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Self { token: self.token.clone() }
+    }
+}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.token.ptr_eq(&other.token)
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+/// Describes the effect of an insert, as returned by [`SkipLinkedList::insert_observed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsertInfo {
+    /// The index the new element was inserted at.
+    pub index: usize,
+    /// The first index whose element shifted one position to the right: every element previously
+    /// at an index `>= shifted_from` now sits at its old index plus one.
+    pub shifted_from: usize,
 }
 
 impl<T> SkipLinkedList<T> {
 
     /// Creates a new list.
+    ///
+    /// Its RNG is seeded from the thread-local generator rather than the OS, so creating many
+    /// short-lived lists stays cheap.
     pub fn new() -> Self {
         Self {
             size: 0,
             entry: Box::new(Node::Sentinel { right: None, down: None, delta: 1}),
+            tail: None,
+            rng: RefCell::new(StdRng::from_rng(thread_rng()).unwrap()),
+            seed: None,
+            probability: 0.5,
+            comparator: None,
+            capacity: None,
+            soft_cap: None,
+            finger_search: false,
+            bulk: false,
+            finger: Cell::new(None),
+            #[cfg(feature = "metrics")]
+            metrics: Cell::new(Metrics::default()),
         }
     }
 
-    /// Inserts an element at position index within the list, shifting all elements after it to the right.
+    /// Creates a new list whose sorted operations (`insert_sorted`, `binary_search`, `lower_bound`
+    /// and `upper_bound`) order elements with `cmp` instead of their `Ord` implementation.
+    ///
+    /// Elements that do not implement `Ord` can be kept sorted with the `_with_comparator` variants
+    /// of those operations.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut list = skip_linked_list::SkipLinkedList::new();
-    /// list.insert(0, 10);
-    /// list.insert(1, 30);
-    /// list.insert(1, 20);
-    /// assert_eq!(list.into_iter().collect::<Vec<i32>>(), vec![10, 20, 30]);
+    /// let mut list = skip_linked_list::SkipLinkedList::new_with_comparator(|a: &i32, b: &i32| b.cmp(a));
+    /// list.insert_sorted(1);
+    /// list.insert_sorted(3);
+    /// list.insert_sorted(2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 2, 1]);
     /// ```
+    pub fn new_with_comparator<F>(cmp: F) -> Self where F: Fn(&T, &T) -> Ordering + 'static {
+        let mut list = Self::new();
+        list.comparator = Some(Rc::new(cmp));
+        list
+    }
+
+    /// Creates a new list holding at most `cap` elements.
     ///
-    /// # Panics
+    /// Pushing onto a full list evicts an element from the opposite end, so the list behaves like a
+    /// ring buffer. Only the push methods enforce the capacity; `insert` and bulk operations may
+    /// grow the list past it.
     ///
-    /// Panics if `i > len`.
-    pub fn insert(&mut self, i: usize, elem: T) {
-        if i > self.size {
-            panic!("insert position {} should be <= len (is {})", i, self.size);
-        }
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::bounded(2);
+    /// assert_eq!(list.push_back_bounded(1), None);
+    /// assert_eq!(list.push_back_bounded(2), None);
+    /// assert_eq!(list.push_back_bounded(3), Some(1));
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 3]);
+    /// ```
+    pub fn bounded(cap: usize) -> Self {
+        let mut list = Self::new();
+        list.capacity = Some(cap);
+        list
+    }
 
-        let i = i + 1; // relative to sentinel
-        let top_level_inserted = Node::insert(&mut self.entry, i, elem);
-        self.size += 1;
-        match (top_level_inserted, thread_rng().gen_bool(0.5)) {
-            (Some(raw_node), true) => {
-                let new_index = Node::Index { right: None, down: raw_node, delta: self.size - i + 1 };
-                let mut entry = Box::new(Node::Sentinel { right: Some(Box::new(new_index)), down: None, delta: i });
-                std::mem::swap(&mut self.entry, &mut entry);
-                match self.entry.as_mut() {
-                    Node::Sentinel { down, .. } => *down = Some(entry),
-                    _ => (),
-                }
+    /// Returns the capacity of a list created with [`bounded`](Self::bounded).
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Sets the length at which `try_push_back` starts rejecting elements, or removes the limit.
+    ///
+    /// Lowering the cap below the current length does not remove any elements.
+    pub fn set_soft_cap(&mut self, soft_cap: Option<usize>) {
+        self.soft_cap = soft_cap;
+    }
+
+    /// Inserts an element at the end of the list, or hands it back if the list has reached its
+    /// soft cap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// list.set_soft_cap(Some(1));
+    /// assert_eq!(list.try_push_back(1), Ok(()));
+    /// assert_eq!(list.try_push_back(2), Err(2));
+    /// ```
+    pub fn try_push_back(&mut self, elem: T) -> Result<(), T> {
+        match self.soft_cap {
+            Some(cap) if self.size >= cap => Err(elem),
+            _ => {
+                self.push_back(elem);
+                Ok(())
             },
-            _ => (),
         }
     }
 
-    /// Gets the element at position index within the list.
+    /// Creates a new list whose tower is shaped by an RNG seeded with `seed`.
+    ///
+    /// Two lists created with the same seed and fed the same operations have identical structures.
+    pub fn with_seed(seed: u64) -> Self {
+        let mut list = Self::new();
+        *list.rng.get_mut() = StdRng::seed_from_u64(seed);
+        list.seed = Some(seed);
+        list
+    }
+
+    /// Builds a list by applying `ops` in order to a list created with [`with_seed`](Self::with_seed).
+    ///
+    /// The result is fully deterministic, which lets a fuzzer replay and shrink a failing case.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut list = skip_linked_list::SkipLinkedList::new();
-    /// list.insert(0, 10);
-    /// assert_eq!(list.get(0), Some(&10));
-    /// assert_eq!(list.get(1), None);
+    /// use skip_linked_list::{ops::Op, SkipLinkedList};
+    ///
+    /// let ops = [Op::Insert(0, 1), Op::Insert(1, 2), Op::Get(0), Op::Remove(0)];
+    /// let list = SkipLinkedList::replay(42, &ops);
+    /// assert_eq!(list.render(), SkipLinkedList::replay(42, &ops).render());
+    /// assert_eq!(list.get(0), Some(&2));
     /// ```
-    pub fn get(&self, i: usize) -> Option<&T> {
-        if i >= self.size {
-            return None;
+    ///
+    /// # Panics
+    ///
+    /// Panics if an `Insert` or `Remove` index is out of bounds when it is applied.
+    pub fn replay(seed: u64, ops: &[Op<T>]) -> Self where T: Clone {
+        let mut list = Self::with_seed(seed);
+        for op in ops {
+            list.apply(op.clone());
         }
-        Node::get(&self.entry, i + 1)
+        list
     }
 
-    /// Removes an element at position index within the list, shifting all elements after it to the left.
+    /// Builds a list by inserting each `(index, value)` in turn, as `insert` would. Each index
+    /// refers to the list as left by the insertions before it.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut list = skip_linked_list::SkipLinkedList::new();
-    /// list.insert(0, 10);
-    /// list.insert(1, 20);
-    /// assert_eq!(list.remove(0), 10);
-    /// assert_eq!(list.remove(0), 20);
+    /// let list = skip_linked_list::SkipLinkedList::from_insertions(vec![(0, 10), (1, 30), (1, 20)]);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![10, 20, 30]);
     /// ```
     ///
     /// # Panics
     ///
-    /// Panics if `i >= len`.
+    /// Panics if an index is greater than the length of the list at that point.
+    pub fn from_insertions<I: IntoIterator<Item = (usize, T)>>(cmds: I) -> Self {
+        let mut list = Self::new();
+        for (i, elem) in cmds {
+            list.insert(i, elem);
+        }
+        list
+    }
 
-    pub fn remove(&mut self, i: usize) -> T {
-        if i >= self.size {
-            panic!("remove position {} should be < len (is {})", i, self.size);
+    /// Applies a single recorded operation to the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_linked_list::{ops::{Op, OpResult}, SkipLinkedList};
+    ///
+    /// let mut list = SkipLinkedList::new();
+    /// assert_eq!(list.apply(Op::Insert(0, 'a')), OpResult::Inserted);
+    /// assert_eq!(list.apply(Op::Get(0)), OpResult::Got(Some(&'a')));
+    /// assert_eq!(list.apply(Op::Remove(0)), OpResult::Removed('a'));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if an `Insert` or `Remove` index is out of bounds.
+    pub fn apply(&mut self, op: Op<T>) -> OpResult<'_, T> {
+        match op {
+            Op::Insert(i, elem) => {
+                self.insert(i, elem);
+                OpResult::Inserted
+            },
+            Op::Remove(i) => OpResult::Removed(self.remove(i)),
+            Op::Get(i) => OpResult::Got(self.get(i)),
         }
-        self.size -= 1;
-        Node::remove(&mut self.entry, i)
     }
 
-    /// Returns the length of the list.
-    pub fn len(&self) -> usize {
-        self.size
+    /// Creates an empty list with the same configuration as this one.
+    fn new_like(&self) -> Self {
+        let mut list = self.new_mapped();
+        list.comparator = self.comparator.clone();
+        list.capacity = self.capacity;
+        list.soft_cap = self.soft_cap;
+        list.bulk = self.bulk;
+        list
     }
 
-    /// Inserts an element at the start of the list.
-    pub fn push_front(&mut self, elem: T) {
-        self.insert(0, elem);
+    /// Creates an empty list with the same configuration as this one: the probability, finger
+    /// search, comparator, capacity and soft cap.
+    ///
+    /// If this list was created with [`with_seed`](Self::with_seed) or last rebuilt with
+    /// [`rebuild_with_seed`](Self::rebuild_with_seed), the new list starts from a fresh RNG with the
+    /// same seed, so the same inserts give it the same tower this list got from its seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::with_seed(3);
+    /// let mut other = list.clone_config();
+    /// list.extend(0..100);
+    /// other.extend(0..100);
+    /// assert_eq!(list.render(), other.render());
+    /// ```
+    pub fn clone_config(&self) -> Self {
+        let mut list = self.new_like();
+        list.bulk = false;
+        if let Some(seed) = self.seed {
+            *list.rng.get_mut() = StdRng::seed_from_u64(seed);
+            list.seed = Some(seed);
+        }
+        list
     }
 
-    /// Inserts an element at the end of the list.
-    pub fn push_back(&mut self, elem: T) {
-        self.insert(self.size, elem);
+    /// Creates an empty list of another element type with the same tuning as this one.
+    fn new_mapped<U>(&self) -> SkipLinkedList<U> {
+        let mut list = SkipLinkedList::new();
+        list.probability = self.probability;
+        list.finger_search = self.finger_search;
+        list
     }
 
-    /// Removes an element at the start of the list.
+    /// Rebuilds the index levels with promotion probability `new_probability`, which is also used
+    /// for all later inserts. The elements themselves are not moved.
+    ///
+    /// A lower probability gives a shorter, sparser tower that uses less memory but makes lookups
+    /// walk further along each level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..1000).collect();
+    /// list.retune(0.25);
+    /// assert_eq!(list.get(500), Some(&500));
+    /// ```
+    ///
     /// # Panics
     ///
-    /// Panics if list is empty.
-    pub fn pop_front(&mut self) -> T {
-        if self.size > 0 {
-            self.remove(0)
-        } else {
-            panic!("can't pop an empty list")
+    /// Panics if `new_probability` is not in `[0, 1)`.
+    pub fn retune(&mut self, new_probability: f64) {
+        if !(0.0..1.0).contains(&new_probability) {
+            panic!("probability should be in [0, 1) (is {})", new_probability);
         }
+        self.probability = new_probability;
+        self.drop_tower();
+        self.build_tower();
     }
 
-    /// Removes an element at the end of the list.
-    /// # Panics
+    /// Rebuilds the index levels with an RNG seeded with `seed`, which is also used for all later
+    /// inserts. The elements themselves are not moved.
     ///
-    /// Panics if list is empty.
-    pub fn pop_back(&mut self) -> T {
-        if self.size > 0 {
-            self.remove(self.size - 1)
-        } else {
-            panic!("can't pop an empty list")
-        }
+    /// Lists with the same elements and probability rebuilt with the same seed have identical
+    /// structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// let mut other = skip_linked_list::SkipLinkedList::new();
+    /// other.extend((0..100).filter(|_| true));
+    /// list.rebuild_with_seed(9);
+    /// other.rebuild_with_seed(9);
+    /// assert_eq!(list.render(), other.render());
+    /// ```
+    pub fn rebuild_with_seed(&mut self, seed: u64) {
+        *self.rng.get_mut() = StdRng::seed_from_u64(seed);
+        self.seed = Some(seed);
+        self.drop_tower();
+        self.build_tower();
     }
 
-    /// Returns an iterator over the list.
-    pub fn iter(&self) -> Iter<T> {
+    /// Returns the number of levels of the list, including the content level.
+    pub fn height(&self) -> usize {
+        let mut height = 1;
         let mut node = self.entry.as_ref();
         while let Node::Sentinel{ down: Some(next_node), .. } = node {
+            height += 1;
             node = next_node;
         }
-        Iter(node.right())
+        height
     }
 
-    /// Returns an mut iterator over the list.
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        let mut node = self.entry.as_mut();
-        while let Node::Sentinel{ down: Some(next_node), .. } = node {
-            node = next_node;
+    /// Returns how many elements have each tower height: index `h` holds the number of elements
+    /// with exactly `h` index nodes above them.
+    ///
+    /// The vector has one entry per level, so its length is [`height`](Self::height).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..1000).collect();
+    /// let histogram = list.height_histogram();
+    /// assert_eq!(histogram.len(), list.height());
+    /// assert_eq!(histogram.iter().sum::<usize>(), 1000);
+    /// ```
+    pub fn height_histogram(&self) -> Vec<usize> {
+        // Towers are contiguous, so the number of nodes on the level `h` above the content level is
+        // the number of elements with at least `h` index nodes.
+        let mut at_least = Vec::new();
+        let mut level = Some(self.entry.as_ref());
+        while let Some(sentinel) = level {
+            let mut count = 0;
+            let mut node = sentinel;
+            while let Some(next_node) = node.right() {
+                count += 1;
+                node = next_node;
+            }
+            at_least.push(count);
+            level = sentinel.down();
         }
-        IterMut(node.right_mut().as_mut())
-    }
-
-    /// Consumes the list into an iterator.
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+        at_least.reverse();
+        let mut histogram: Vec<usize> = at_least.windows(2).map(|pair| pair[0] - pair[1]).collect();
+        histogram.extend(at_least.last());
+        histogram
     }
-}
-
-pub struct IntoIter<T>(SkipLinkedList<T>);
-
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.0.len() > 0 {
-            Some(self.0.pop_front())
-        } else {
-            None
+    /// Returns how many elements each node on `level` spans, from its own element up to the next
+    /// node's, or `None` if the list has no such level. Level 0 is the content level.
+    ///
+    /// The elements before the first node on the level are counted as one leading span, so the
+    /// spans always add up to `len`, and every span on the content level is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// assert_eq!(list.level_spans(0), Some(vec![1; 100]));
+    /// assert_eq!(list.level_spans(1).unwrap().iter().sum::<usize>(), 100);
+    /// assert_eq!(list.level_spans(list.height()), None);
+    /// ```
+    pub fn level_spans(&self, level: usize) -> Option<Vec<usize>> {
+        if level >= self.height() {
+            return None;
+        }
+        let mut sentinel = self.entry.as_ref();
+        for _ in level + 1..self.height() {
+            sentinel = sentinel.down().unwrap();
         }
+        let mut spans: Vec<usize> = Some(sentinel.delta() - 1).filter(|&span| span > 0).into_iter().collect();
+        let mut node = sentinel;
+        while let Some(next_node) = node.right() {
+            spans.push(next_node.delta());
+            node = next_node;
+        }
+        Some(spans)
     }
-}
-
-pub struct IterMut<'a, T>(Option<&'a mut Link<T>>);
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.take().and_then(|node| {
-            if let Node::Content { elem, right } = node.as_mut() {
-                self.0 = right.as_mut();
-                Some(elem)
-            } else {
-                None
+    /// Counts the nodes of each kind across all levels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// let counts = list.node_counts();
+    /// assert_eq!(counts.content, 100);
+    /// assert_eq!(counts.sentinel, list.height());
+    /// ```
+    pub fn node_counts(&self) -> NodeCounts {
+        let mut counts = NodeCounts { content: 0, index: 0, sentinel: 0 };
+        let mut level = Some(self.entry.as_ref());
+        while let Some(sentinel) = level {
+            let mut node = Some(sentinel);
+            while let Some(current) = node {
+                match current {
+                    Node::Sentinel { .. } => counts.sentinel += 1,
+                    Node::Index { .. } => counts.index += 1,
+                    Node::Content { .. } => counts.content += 1,
+                }
+                node = current.right().map(|next_node| next_node.as_ref());
             }
-        })
+            level = sentinel.down();
+        }
+        counts
     }
-}
 
-pub struct Iter<'a, T>(Option<&'a Link<T>>);
-
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Drops every index level, leaving the content level only.
+    fn drop_tower(&mut self) {
+        self.flatten_levels(1);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.take().and_then(|node| {
-            if let Node::Content { elem, right } = node.as_ref() {
-                self.0 = right.as_ref();
-                Some(elem)
-            } else {
-                None
+    /// Moves the elements out in order, dropping the index levels and then walking the content
+    /// level once, in `O(n)` time.
+    pub(crate) fn into_vec(mut self) -> Vec<T> {
+        self.drop_tower();
+        let mut elems = Vec::with_capacity(self.size);
+        let mut right = self.entry.right_mut().take();
+        while let Some(mut node) = right {
+            right = node.right_mut().take();
+            if let Node::Content { elem, .. } = *node {
+                elems.push(elem);
             }
-        })
+        }
+        self.size = 0;
+        self.tail = None;
+        elems
     }
-}
-
-const WIDTH: usize = 4;
 
-impl<T> SkipLinkedList<T> where T: Display {
+    /// Links the elements in after the last one and rebuilds the tower around them.
+    fn append_bulk<I: Iterator<Item = T>>(&mut self, iter: I) {
+        self.drop_tower();
+        self.append_content(iter);
+        self.build_tower();
+    }
 
-    /// Prints the internals of the list.
-    pub fn visualize(&self) {
-        let mut option_node = Some(&self.entry);
-        while let Some(node) = option_node.take() {
-            Self::visualize_level(Some(node));
-            match node.as_ref() {
-                Node::Sentinel { down, .. } => option_node = down.as_ref(),
-                _ => break,
-            }
+    /// Links the elements of `iter` after the tail of a list that consists of the content level
+    /// only, without building any index levels.
+    fn append_content<I: Iterator<Item = T>>(&mut self, iter: I) {
+        let mut last = match self.tail {
+            Some(raw_node) => raw_node,
+            None => NonNull::from(self.bottom_mut()),
+        };
+        for elem in iter {
+            last = unsafe { last.as_mut() }.insert_content_after(elem).unwrap();
+            self.size += 1;
+        }
+        if self.size > 0 {
+            self.tail = Some(last);
         }
     }
 
-    fn visualize_level(option_node: Option<&Box<Node<T>>>) {
-        let mut option_node = option_node;
-        let mut last_delta = 0;
-        while let Some(node) = option_node.take() {
-            match node.as_ref() {
-                Node::Sentinel { right, delta, .. } => {
-                    print!("{delta:>width$}", delta=format!("+{}", delta), width=WIDTH);
-                    last_delta = *delta;
-                    option_node = right.as_ref();
-                },
-                Node::Index { right, delta, .. } => {
-                    print!("{delta:>width$}", delta=format!("+{}", delta), width=(last_delta*WIDTH));
-                    last_delta = *delta;
-                    option_node = right.as_ref();
-                },
-                Node::Content { right, elem, .. } => {
-                    print!("{elem:>width$}", elem=elem, width=WIDTH);
-                    option_node = right.as_ref();
+    /// Builds the index levels on top of a list that consists of the content level only.
+    fn build_tower(&mut self) {
+        let size = self.size;
+        let mut lower = Vec::with_capacity(size);
+        let mut node = self.entry.as_mut();
+        loop {
+            node.relink_right();
+            match node.right_mut() {
+                Some(next_node) => {
+                    lower.push((lower.len() + 1, NonNull::from(next_node.as_mut())));
+                    node = next_node;
                 },
+                None => break,
             }
         }
-        println!();
-    }
-}
+        self.tail = lower.last().map(|(_, raw_node)| *raw_node);
 
-impl<T> Node<T> {
-    fn right_mut(&mut self) -> &mut Option<Link<T>> {
-        match self {
-            Node::Sentinel { right, .. } => right,
-            Node::Content { right, .. }  => right,
-            Node::Index { right, .. } => right,
+        loop {
+            let rng = self.rng.get_mut();
+            let probability = self.probability;
+            let promoted: Vec<_> = lower.into_iter().filter(|_| rng.gen_bool(probability)).collect();
+            if promoted.is_empty() {
+                break;
+            }
+            lower = self.push_index_level(promoted);
         }
     }
 
-    fn right(&self) -> Option<&Link<T>> {
-        match self {
-            Node::Sentinel { right, .. } => right.as_ref(),
-            Node::Content { right, .. }  => right.as_ref(),
-            Node::Index { right, .. } => right.as_ref(),
+    /// Adds a level on top of the tower with an index node above each of the `promoted` nodes,
+    /// given with their positions in order. Returns the new index nodes with their positions.
+    fn push_index_level(&mut self, promoted: Vec<(usize, WeakLink<T>)>) -> Vec<(usize, WeakLink<T>)> {
+        let mut upper = Vec::with_capacity(promoted.len());
+        let mut right = None;
+        let mut next_pos = self.size + 1;
+        for (pos, mut down) in promoted.into_iter().rev() {
+            let mut new_node = Box::new(Node::Index { right, prev: NonNull::dangling(), down, up: None, delta: next_pos - pos });
+            let raw_new_node = NonNull::from(new_node.as_mut());
+            unsafe { down.as_mut() }.set_up(Some(raw_new_node));
+            upper.push((pos, raw_new_node));
+            right = Some(new_node);
+            next_pos = pos;
         }
-    }
-
-    fn insert(start_node: &mut Node<T>, start_i: usize, elem: T) -> Option<WeakLink<T>> {
+        upper.reverse();
+        let entry = std::mem::replace(&mut self.entry, Box::new(Node::Sentinel { right, down: None, delta: next_pos }));
+        if let Node::Sentinel { down, .. } = self.entry.as_mut() {
+            *down = Some(entry);
+        }
+        let mut node = self.entry.as_mut();
+        loop {
+            node.relink_right();
+            match node.right_mut() {
+                Some(next_node) => node = next_node,
+                None => break,
+            }
+        }
+        upper
+    }
+
+    /// Enables or disables finger search.
+    ///
+    /// When enabled, `get` remembers the last element it reached, and a later `get` to an index
+    /// shortly after it walks forward from there instead of descending from the top. This makes
+    /// sequential or localized reads close to `O(1)` each. Any insert or remove forgets the finger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// for i in 0..100 {
+    ///     list.push_back(i);
+    /// }
+    /// list.enable_finger_search(true);
+    /// for i in 0..100 {
+    ///     assert_eq!(list.get(i), Some(&i));
+    /// }
+    /// ```
+    pub fn enable_finger_search(&mut self, enabled: bool) {
+        self.finger_search = enabled;
+        self.finger.set(None);
+    }
+
+    /// Enters bulk mode, in which `insert` and `remove` only edit the content level.
+    ///
+    /// The index levels are dropped, and each write walks from the position of the previous one,
+    /// so runs of writes that move forward through the list take `O(1)` each. Reads stay correct
+    /// but walk the content level until [`end_bulk`](Self::end_bulk) rebuilds the tower.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<i32> = (0..100).collect();
+    /// list.begin_bulk();
+    /// for i in 0..100 {
+    ///     list.insert(2 * i + 1, -(i as i32));
+    /// }
+    /// list.end_bulk();
+    /// assert_eq!(list.get(5), Some(&-2));
+    /// ```
+    pub fn begin_bulk(&mut self) {
+        self.drop_tower();
+        self.bulk = true;
+    }
+
+    /// Leaves bulk mode, rebuilding the index levels in `O(n)`.
+    pub fn end_bulk(&mut self) {
+        if self.bulk {
+            self.bulk = false;
+            self.drop_tower();
+            self.build_tower();
+        }
+    }
+
+    /// Inserts an element on the content level only, as in bulk mode.
+    fn insert_bulk(&mut self, i: usize, elem: T) {
+        let mut visits = 0;
+        let mut prev = self.content_before(i, &mut visits);
+        #[cfg(feature = "metrics")]
+        self.record(0, visits);
+        let new_node = unsafe { prev.as_mut() }.insert_content_after(elem).unwrap();
+        self.size += 1;
+        if i + 1 == self.size {
+            self.tail = Some(new_node);
+        }
+        self.finger.set(Some((i, new_node)));
+    }
+
+    /// Removes an element from the content level only, as in bulk mode.
+    fn remove_bulk(&mut self, i: usize) -> T {
+        let mut visits = 0;
+        let mut prev = self.content_before(i, &mut visits);
+        #[cfg(feature = "metrics")]
+        self.record(0, visits);
+        let elem = unsafe { prev.as_mut() }.remove_right().unwrap();
+        self.size -= 1;
+        if i == self.size {
+            self.tail = if i > 0 { Some(prev) } else { None };
+        }
+        self.finger.set(if i > 0 { Some((i - 1, prev)) } else { None });
+        elem
+    }
+
+    /// Returns the node at position `pos` of the content level, walking from the finger when it
+    /// is not past `pos`, and adds the number of links followed to `visits`. Any index levels are
+    /// dropped first.
+    fn content_before(&mut self, pos: usize, visits: &mut usize) -> WeakLink<T> {
+        if self.height() > 1 {
+            self.drop_tower();
+        }
+        if let (true, Some(raw_node)) = (pos == self.size, self.tail) {
+            return raw_node;
+        }
+        let (mut node_pos, mut raw_node) = match self.finger.get() {
+            Some((j, raw_node)) if j < pos => (j + 1, raw_node),
+            _ => (0, NonNull::from(self.bottom_mut())),
+        };
+        while node_pos < pos {
+            let node = unsafe { raw_node.as_mut() }.right_mut().as_mut().unwrap();
+            raw_node = NonNull::from(node.as_mut());
+            node_pos += 1;
+            *visits += 1;
+        }
+        raw_node
+    }
+
+    /// Inserts an element at position index within the list, shifting all elements after it to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// list.insert(0, 10);
+    /// list.insert(1, 30);
+    /// list.insert(1, 20);
+    /// assert_eq!(list.into_iter().collect::<Vec<i32>>(), vec![10, 20, 30]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i > len`.
+    pub fn insert(&mut self, i: usize, elem: T) {
+        if i > self.size {
+            panic!("insert position {} should be <= len (is {})", i, self.size);
+        }
+        if self.bulk {
+            return self.insert_bulk(i, elem);
+        }
+
+        self.finger.set(None);
+        let appending = i == self.size;
+        let i = i + 1; // relative to sentinel
+        let mut visits = 0;
+        let top_level_inserted = Node::insert(&mut self.entry, i, elem, self.rng.get_mut(), self.probability, &mut visits);
+        #[cfg(feature = "metrics")]
+        self.record(1, visits);
+        self.size += 1;
+        if appending {
+            let last = match self.tail {
+                Some(mut raw_node) => unsafe { raw_node.as_mut() },
+                None => self.bottom_mut(),
+            };
+            self.tail = last.right_mut().as_mut().map(|node| NonNull::from(node.as_mut()));
+        }
+        if let (Some(mut raw_node), true) = (top_level_inserted, self.rng.get_mut().gen_bool(self.probability)) {
+            let mut new_index = Box::new(Node::Index { right: None, prev: NonNull::dangling(), down: raw_node, up: None, delta: self.size - i + 1 });
+            unsafe { raw_node.as_mut() }.set_up(Some(NonNull::from(new_index.as_mut())));
+            let mut entry = Box::new(Node::Sentinel { right: Some(new_index), down: None, delta: i });
+            std::mem::swap(&mut self.entry, &mut entry);
+            if let Node::Sentinel { down, .. } = self.entry.as_mut() {
+                *down = Some(entry);
+            }
+            self.entry.relink_right();
+        }
+    }
+
+    /// Inserts an element so that it ends up `from_end` positions from the back: 0 appends it, 1
+    /// places it before the last element, and `len` places it first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1, 2].into_iter().collect();
+    /// list.insert_from_back(1, 9);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 9, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from_end > len`.
+    pub fn insert_from_back(&mut self, from_end: usize, elem: T) {
+        if from_end > self.size {
+            panic!("insert offset {} from the back should be <= len (is {})", from_end, self.size);
+        }
+        self.insert(self.size - from_end, elem);
+    }
+
+    /// Inserts at index `i` the average of its would-be neighbours, the elements currently at `i - 1`
+    /// and `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<i32> = vec![10, 30].into_iter().collect();
+    /// list.insert_interpolated(1);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![10, 20, 30]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 < i < len`, since both neighbours must exist.
+    pub fn insert_interpolated(&mut self, i: usize) where T: Add<Output = T> + Div<Output = T> + From<u8> + Copy {
+        if i == 0 || i >= self.size {
+            panic!("interpolation position {} should be in (0, len) (len is {})", i, self.size);
+        }
+        let (before, after) = (*self.get(i - 1).unwrap(), *self.get(i).unwrap());
+        self.insert(i, (before + after) / T::from(2));
+    }
+
+    /// Inserts `items` at evenly spaced positions across the list.
+    ///
+    /// With `n` elements in the list and `m` items, item `k` (counting from 0) ends up at index
+    /// `(k + 1) * (n + m) / (m + 1)`, rounded down, so the items split the resulting list into
+    /// `m + 1` runs of nearly equal length. Each insert takes `O(log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..6).collect();
+    /// list.insert_evenly(vec![10, 20, 30]);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 10, 2, 20, 3, 30, 4, 5]);
+    /// ```
+    pub fn insert_evenly<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        let items: Vec<T> = items.into_iter().collect();
+        let total = self.size + items.len();
+        let runs = items.len() + 1;
+        for (k, item) in items.into_iter().enumerate() {
+            self.insert((k + 1) * total / runs, item);
+        }
+    }
+
+    /// Inserts an element like `insert`, and reports which positions shifted as a result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_linked_list::list::InsertInfo;
+    ///
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1, 3].into_iter().collect();
+    /// let info = list.insert_observed(1, 2);
+    /// assert_eq!(info, InsertInfo { index: 1, shifted_from: 1 });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i > len`.
+    pub fn insert_observed(&mut self, i: usize, elem: T) -> InsertInfo {
+        self.insert(i, elem);
+        InsertInfo { index: i, shifted_from: i }
+    }
+
+    /// Gets the element at position index within the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// list.insert(0, 10);
+    /// assert_eq!(list.get(0), Some(&10));
+    /// assert_eq!(list.get(1), None);
+    /// ```
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.size {
+            #[cfg(feature = "metrics")]
+            self.record(0, 0);
+            return None;
+        }
+        let mut visits = 0;
+        if !self.finger_search {
+            let elem = Node::get(&self.entry, i + 1, &mut visits);
+            #[cfg(feature = "metrics")]
+            self.record(1, visits);
+            return elem;
+        }
+
+        let node = match self.finger.get() {
+            Some((j, raw_node)) if j <= i && i - j <= FINGER_REACH => {
+                #[cfg(feature = "metrics")]
+                self.record(0, i - j);
+                let mut node = unsafe { raw_node.as_ref() };
+                for _ in j..i {
+                    node = node.right().unwrap();
+                }
+                node
+            },
+            _ => {
+                let node = Node::seek(&self.entry, i + 1, &mut visits);
+                #[cfg(feature = "metrics")]
+                self.record(1, visits);
+                node
+            },
+        };
+        self.finger.set(Some((i, NonNull::from(node))));
+        node.elem()
+    }
+
+    /// Gets the element at position index as a borrowed `Cow`, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.get_cow(1), Some(Cow::Borrowed(&2)));
+    /// ```
+    pub fn get_cow(&self, i: usize) -> Option<Cow<'_, T>> where T: Clone {
+        self.get(i).map(Cow::Borrowed)
+    }
+
+    /// Returns the number of links a descent from the top follows to reach index `i`, counting
+    /// both right and down links.
+    ///
+    /// This is the cost of `get` without finger search. An out of bounds index costs nothing, as
+    /// `get` returns before walking the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// list.retune(0.0);
+    /// assert_eq!(list.lookup_cost(50), 51);
+    /// ```
+    pub fn lookup_cost(&self, i: usize) -> usize {
+        if i >= self.size {
+            return 0;
+        }
+        self.descent_cost(i + 1)
+    }
+
+    /// Returns the index with the highest [`lookup_cost`](Self::lookup_cost), the last one on a tie,
+    /// along with that cost. An empty list gives `(0, 0)`.
+    ///
+    /// The costs are worked out level by level from the top in one pass over the tower, in `O(n)`
+    /// time: a node is reached either from the node above it or from its left neighbour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// list.retune(0.0);
+    /// assert_eq!(list.worst_case_lookup(), (99, 100));
+    /// ```
+    pub fn worst_case_lookup(&self) -> (usize, usize) {
+        // The positions and costs of the nodes on the level above, from left to right.
+        let mut above: Vec<(usize, usize)> = Vec::new();
+        let mut sentinel = Some(self.entry.as_ref());
+        while let Some(first) = sentinel {
+            let mut level = Vec::new();
+            let mut upper = above.iter().peekable();
+            let (mut node, mut pos, mut cost) = (Some(first), 0, 0);
+            while let Some(current) = node {
+                while matches!(upper.peek(), Some(&&(upper_pos, _)) if upper_pos < pos) {
+                    upper.next();
+                }
+                cost = match upper.peek() {
+                    Some(&&(upper_pos, upper_cost)) if upper_pos == pos => upper_cost + 1,
+                    _ if pos == 0 => 0,
+                    _ => cost + 1,
+                };
+                level.push((pos, cost));
+                pos += current.delta();
+                node = current.right().map(|next_node| next_node.as_ref());
+            }
+            above = level;
+            sentinel = first.down();
+        }
+        above.into_iter().skip(1).map(|(pos, cost)| (pos - 1, cost)).max_by_key(|&(_, cost)| cost).unwrap_or((0, 0))
+    }
+
+    /// Returns the levels on which a descent from the top to index `i` moves right, from the top
+    /// down, with 0 being the content level. Levels the descent drops straight through are left
+    /// out, so the result shows which parts of the tower did the work. An out of bounds index
+    /// gives an empty path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// list.retune(0.0);
+    /// assert_eq!(list.path_levels(50), vec![0]);
+    /// ```
+    pub fn path_levels(&self, i: usize) -> Vec<usize> {
+        let mut levels = Vec::new();
+        if i >= self.size {
+            return levels;
+        }
+        let mut node = self.entry.as_ref();
+        let mut pos = i + 1;
+        let mut level = self.height();
+        loop {
+            level -= 1;
+            if node.delta() <= pos {
+                levels.push(level);
+            }
+            while node.delta() <= pos {
+                pos -= node.delta();
+                node = node.right().unwrap();
+            }
+            node = match node.down() {
+                Some(next_node) => next_node,
+                None => return levels,
+            };
+        }
+    }
+
+    /// Returns the number of links followed by a descent from the top to position `pos`.
+    fn descent_cost(&self, pos: usize) -> usize {
+        let mut node = self.entry.as_ref();
+        let mut i = pos;
+        let mut cost = 0;
+        loop {
+            while node.delta() <= i {
+                i -= node.delta();
+                node = node.right().unwrap();
+                cost += 1;
+            }
+            node = match node.down() {
+                Some(next_node) => next_node,
+                None => return cost,
+            };
+            cost += 1;
+        }
+    }
+
+    /// Returns the traversal counts accumulated by `insert`, `get` and `remove` since the list was
+    /// created.
+    ///
+    /// The links are counted as the operations follow them. A `get` served by finger search walks
+    /// from the finger without a descent, and so do writes in bulk mode, which walk the content
+    /// level.
+    #[cfg(feature = "metrics")]
+    pub fn operation_metrics(&self) -> Metrics {
+        self.metrics.get()
+    }
+
+    #[cfg(feature = "metrics")]
+    fn record(&self, descents: usize, node_visits: usize) {
+        let mut metrics = self.metrics.get();
+        metrics.total_descents += descents;
+        metrics.total_node_visits += node_visits;
+        metrics.op_count += 1;
+        self.metrics.set(metrics);
+    }
+
+    /// Gets the element at position index, or `default` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.get_or(1, &0), &2);
+    /// assert_eq!(list.get_or(10, &0), &0);
+    /// ```
+    pub fn get_or<'a>(&'a self, i: usize, default: &'a T) -> &'a T {
+        self.get(i).unwrap_or(default)
+    }
+
+    /// Gets the element at position index, or the result of `f` if the index is out of bounds.
+    /// `f` is only called in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.get_or_else(10, || &0), &0);
+    /// ```
+    pub fn get_or_else<'a, F: FnOnce() -> &'a T>(&'a self, i: usize, f: F) -> &'a T {
+        self.get(i).unwrap_or_else(f)
+    }
+
+    /// Gets the element at position index, clamping an out of bounds index to the last element.
+    ///
+    /// Returns `None` only if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.get_saturating(1), Some(&2));
+    /// assert_eq!(list.get_saturating(10), Some(&3));
+    /// ```
+    pub fn get_saturating(&self, i: usize) -> Option<&T> {
+        if self.size == 0 {
+            return None;
+        }
+        self.get(i.min(self.size - 1))
+    }
+
+    /// Gets the element at position index modulo the length of the list.
+    ///
+    /// Returns `None` only if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.get_wrapping(1), Some(&2));
+    /// assert_eq!(list.get_wrapping(10), Some(&2));
+    /// ```
+    pub fn get_wrapping(&self, i: usize) -> Option<&T> {
+        if self.size == 0 {
+            return None;
+        }
+        self.get(i % self.size)
+    }
+
+    /// Sets the element at position index to `new` if it currently equals `expected`.
+    ///
+    /// Returns whether the element was replaced; an out of bounds index never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// list.push_back(10);
+    /// assert!(!list.compare_and_set(0, &20, 30));
+    /// assert!(list.compare_and_set(0, &10, 30));
+    /// assert_eq!(list.get(0), Some(&30));
+    /// ```
+    pub fn compare_and_set(&mut self, i: usize, expected: &T, new: T) -> bool where T: PartialEq {
+        if i >= self.size {
+            return false;
+        }
+        match Node::get_mut(&mut self.entry, i + 1) {
+            Some(elem) if *elem == *expected => {
+                *elem = new;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Swaps the elements at `i` and `i + 1`, in `O(log n)` time. Only the elements move; the
+    /// tower is left as it is.
+    ///
+    /// Since the two values trade places in memory, a pointer to either element stays with its
+    /// position, not its value: afterwards it points at the other value, as seen by
+    /// [`index_of_ptr`](Self::index_of_ptr). Handles from `push_front_handle` do follow their
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..4).collect();
+    /// list.swap_adjacent(1);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 2, 1, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i + 1 >= len`.
+    pub fn swap_adjacent(&mut self, i: usize) {
+        if i + 1 >= self.size {
+            panic!("swap position {} should be < len - 1 (len is {})", i, self.size);
+        }
+        let node = Node::seek_mut(&mut self.entry, i + 1);
+        let raw_node = NonNull::from(&mut *node);
+        if let Node::Content { right: Some(next_node), elem, handle, .. } = node {
+            let raw_next_node = NonNull::from(next_node.as_mut());
+            if let Node::Content { elem: next, handle: next_handle, .. } = next_node.as_mut() {
+                std::mem::swap(elem, next);
+                std::mem::swap(handle, next_handle);
+                if let Some(slot) = handle {
+                    slot.set(raw_node);
+                }
+                if let Some(slot) = next_handle {
+                    slot.set(raw_next_node);
+                }
+            }
+        }
+    }
+
+    /// Removes an element at position index within the list, shifting all elements after it to the left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// list.insert(0, 10);
+    /// list.insert(1, 20);
+    /// assert_eq!(list.remove(0), 10);
+    /// assert_eq!(list.remove(0), 20);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= len`.
+    pub fn remove(&mut self, i: usize) -> T {
+        if i >= self.size {
+            panic!("remove position {} should be < len (is {})", i, self.size);
+        }
+        if self.bulk {
+            return self.remove_bulk(i);
+        }
+        if i == self.size - 1 {
+            self.tail = self.tail.and_then(|raw_node| match unsafe { raw_node.as_ref() } {
+                Node::Content { prev, .. } => match unsafe { prev.as_ref() } {
+                    Node::Content { .. } => Some(*prev),
+                    _ => None,
+                },
+                _ => None,
+            });
+        }
+        self.size -= 1;
+        self.finger.set(None);
+        let mut visits = 0;
+        let elem = Node::remove(&mut self.entry, i, &mut visits);
+        #[cfg(feature = "metrics")]
+        self.record(1, visits);
+        elem
+    }
+
+    /// Returns the length of the list.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the list contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// assert!(list.is_empty());
+    /// list.push_front(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Inserts an element at the start of the list.
+    ///
+    /// On a full bounded list, the last element is evicted and dropped.
+    pub fn push_front(&mut self, elem: T) {
+        self.push_front_bounded(elem);
+    }
+
+    /// Inserts an element at the end of the list.
+    ///
+    /// On a full bounded list, the first element is evicted and dropped.
+    pub fn push_back(&mut self, elem: T) {
+        self.push_back_bounded(elem);
+    }
+
+    /// Inserts an element at the start of the list, returning the last element if it had to be
+    /// evicted to stay within the capacity.
+    ///
+    /// A list with capacity 0 hands the element straight back.
+    pub fn push_front_bounded(&mut self, elem: T) -> Option<T> {
+        match self.capacity {
+            Some(0) => Some(elem),
+            Some(cap) if self.size >= cap => {
+                let evicted = self.remove(self.size - 1);
+                self.insert(0, elem);
+                Some(evicted)
+            },
+            _ => {
+                self.insert(0, elem);
+                None
+            },
+        }
+    }
+
+    /// Inserts an element at the end of the list, returning the first element if it had to be
+    /// evicted to stay within the capacity.
+    ///
+    /// A list with capacity 0 hands the element straight back.
+    pub fn push_back_bounded(&mut self, elem: T) -> Option<T> {
+        match self.capacity {
+            Some(0) => Some(elem),
+            Some(cap) if self.size >= cap => {
+                let evicted = self.remove(0);
+                self.insert(self.size, elem);
+                Some(evicted)
+            },
+            _ => {
+                self.insert(self.size, elem);
+                None
+            },
+        }
+    }
+
+    /// Moves the element at index `i` to the start of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..4).collect();
+    /// list.move_to_front(2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 0, 1, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= len`.
+    pub fn move_to_front(&mut self, i: usize) {
+        let elem = self.remove(i);
+        self.push_front(elem);
+    }
+
+    /// Moves the element at index `i` to the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= len`.
+    pub fn move_to_back(&mut self, i: usize) {
+        let elem = self.remove(i);
+        self.push_back(elem);
+    }
+
+    /// Inserts an element at the start of the list and returns a handle to it, which stays valid
+    /// while the element is in the list no matter how its index shifts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// let handle = list.push_front_handle(2);
+    /// list.push_front(1);
+    /// list.push_back(3);
+    /// assert_eq!(list.remove_handle(handle), Some(2));
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    pub fn push_front_handle(&mut self, elem: T) -> Handle<T> {
+        self.insert(0, elem);
+        let node = self.bottom_mut().right_mut().as_mut().unwrap();
+        let token = Rc::new(Cell::new(NonNull::from(node.as_mut())));
+        let handle = Handle { token: Rc::downgrade(&token) };
+        if let Node::Content { handle: slot, .. } = node.as_mut() {
+            *slot = Some(token);
+        }
+        handle
+    }
+
+    /// Removes the element identified by `handle` and returns it, or `None` if it is not in the
+    /// list.
+    ///
+    /// The element's index is found by following links right and up the tower from its node, and
+    /// the removal descends from the top as usual, so both take `O(log n)` expected time. A handle
+    /// whose element was already removed returns `None`.
+    pub fn remove_handle(&mut self, handle: Handle<T>) -> Option<T> {
+        let i = self.handle_index(&handle)?;
+        Some(self.remove(i))
+    }
+
+    /// Returns the index of the element identified by `handle`, or `None` if it is not in the list.
+    fn handle_index(&self, handle: &Handle<T>) -> Option<usize> {
+        // The node owns the slot, so it is still allocated while the slot is.
+        let raw_node = handle.token.upgrade()?.get();
+        let mut node = unsafe { raw_node.as_ref() };
+        let mut level = 0;
+        let mut offset = 0;
+        loop {
+            if let Some(upper_node) = node.up() {
+                node = upper_node;
+                level += 1;
+                continue;
+            }
+            offset += node.delta();
+            match node.right() {
+                Some(next_node) => node = next_node,
+                None => break,
+            }
+        }
+        // Every level spans `len + 1` positions, so `offset` counts the positions from the node to
+        // the end of its list. The last node reached tells whether that list is this one.
+        if std::ptr::eq(node, self.last_on_level(level)?) {
+            Some(self.size - offset)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the last node on the level `level` places above the content level, or `None` if the
+    /// tower is not that high.
+    fn last_on_level(&self, level: usize) -> Option<&Node<T>> {
+        let height = self.height();
+        if level >= height {
+            return None;
+        }
+        let mut node = self.entry.as_ref();
+        for _ in level + 1..height {
+            while let Some(next_node) = node.right() {
+                node = next_node;
+            }
+            node = node.down().unwrap();
+        }
+        while let Some(next_node) = node.right() {
+            node = next_node;
+        }
+        Some(node)
+    }
+
+    /// Returns the last element of the list, or `None` if it is empty. This takes `O(1)` time.
+    pub fn back(&self) -> Option<&T> {
+        self.tail.and_then(|raw_node| unsafe { raw_node.as_ref() }.elem())
+    }
+
+    /// Returns a mutable reference to the last element of the list, or `None` if it is empty.
+    /// This takes `O(1)` time.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        match self.tail {
+            Some(mut raw_node) => match unsafe { raw_node.as_mut() } {
+                Node::Content { elem, .. } => Some(elem),
+                _ => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Removes an element at the start of the list.
+    /// # Panics
+    ///
+    /// Panics if list is empty.
+    pub fn pop_front(&mut self) -> T {
+        if self.size > 0 {
+            self.remove(0)
+        } else {
+            panic!("can't pop an empty list")
+        }
+    }
+
+    /// Removes an element at the end of the list.
+    ///
+    /// The last element's column is unlinked upwards from the tail, without a descent from the
+    /// top, so the content level is updated in `O(1)`. The levels above the column still end one
+    /// position earlier; their last nodes are reached by walking left and up, in `O(log n)`
+    /// expected time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if list is empty.
+    pub fn pop_back(&mut self) -> T {
+        let mut raw_node = match self.tail {
+            Some(raw_node) => raw_node,
+            None => panic!("can't pop an empty list"),
+        };
+        self.finger.set(None);
+
+        // Each node of the last column is the last on its level and spans one position, so the
+        // node to its left keeps its span and becomes the last one.
+        let mut popped = None;
+        let mut level = 0;
+        let mut node = loop {
+            let (mut raw_prev, up) = match unsafe { raw_node.as_ref() } {
+                Node::Index { prev, up, .. } | Node::Content { prev, up, .. } => (*prev, *up),
+                Node::Sentinel { .. } => unreachable!(),
+            };
+            let removed = unsafe { raw_prev.as_mut() }.right_mut().take().unwrap();
+            if let Node::Content { elem, .. } = *removed {
+                popped = Some(elem);
+                self.tail = match unsafe { raw_prev.as_ref() } {
+                    Node::Content { .. } => Some(raw_prev),
+                    _ => None,
+                };
+            }
+            level += 1;
+            match up {
+                Some(raw_up) => raw_node = raw_up,
+                None => break raw_prev,
+            }
+        };
+
+        let height = self.height();
+        while level < height {
+            loop {
+                node = match unsafe { node.as_ref() } {
+                    Node::Index { up: Some(_), .. } | Node::Content { up: Some(_), .. } => break,
+                    Node::Index { prev, .. } | Node::Content { prev, .. } => *prev,
+                    Node::Sentinel { .. } => {
+                        // Nothing is left on the levels above but their sentinels.
+                        let mut sentinel = Some(self.entry.as_mut());
+                        for _ in level..height {
+                            let current = sentinel.unwrap();
+                            *current.delta_mut().unwrap() -= 1;
+                            sentinel = match current {
+                                Node::Sentinel { down, .. } => down.as_deref_mut(),
+                                _ => None,
+                            };
+                        }
+                        self.size -= 1;
+                        return popped.unwrap();
+                    },
+                };
+            }
+            node = match unsafe { node.as_ref() } {
+                Node::Index { up: Some(raw_up), .. } | Node::Content { up: Some(raw_up), .. } => *raw_up,
+                _ => unreachable!(),
+            };
+            *unsafe { node.as_mut() }.delta_mut().unwrap() -= 1;
+            level += 1;
+        }
+        self.size -= 1;
+        popped.unwrap()
+    }
+
+    /// Splits the list into two at the given index.
+    ///
+    /// Returns a newly allocated list containing the elements in the range `[at, len)`, and leaves
+    /// `self` with the elements `[0, at)`. Every level of the tower is cut in place, so this takes
+    /// `O(log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// let tail = list.split_off(2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1]);
+    /// assert_eq!(tail.into_iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at > self.size {
+            panic!("split position {} should be <= len (is {})", at, self.size);
+        }
+        self.finger.set(None);
+
+        let mut tails = Vec::new();
+        let mut node = self.entry.as_mut();
+        let mut pos = 0;
+        let last = loop {
+            while pos + node.delta() <= at {
+                pos += node.delta();
+                node = node.right_mut().as_mut().unwrap();
+            }
+            let tail = node.right_mut().take();
+            tails.push((tail, pos + node.delta() - at));
+            if let Some(delta) = node.delta_mut() {
+                *delta = at + 1 - pos;
+            }
+            node = match node {
+                Node::Sentinel { down: Some(next_node), .. } => next_node,
+                Node::Index { down: raw_node, .. } => unsafe { raw_node.as_mut() },
+                Node::Sentinel { .. } => break None,
+                Node::Content { .. } => break Some(NonNull::from(node)),
+            };
+        };
+
+        let mut entry = None;
+        for (right, delta) in tails.into_iter().rev() {
+            let mut sentinel = Box::new(Node::Sentinel { right, down: entry, delta });
+            sentinel.relink_right();
+            entry = Some(sentinel);
+        }
+        let mut other = self.new_like();
+        other.entry = entry.unwrap();
+        other.size = self.size - at;
+        if other.size > 0 {
+            other.tail = self.tail;
+        }
+        self.tail = last;
+        self.size = at;
+        other
+    }
+
+    /// Moves all elements of `other` to the end of the list, leaving `other` empty.
+    ///
+    /// Each level of `other` is linked after the last node of the same level, so this takes
+    /// `O(log n + log m)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..3).collect();
+    /// let mut other: skip_linked_list::SkipLinkedList<_> = (3..5).collect();
+    /// list.append(&mut other);
+    /// assert_eq!(other.len(), 0);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.finger.set(None);
+        other.finger.set(None);
+        if other.size == 0 {
+            return;
+        }
+        while self.height() < other.height() {
+            self.push_level();
+        }
+        while other.height() < self.height() {
+            other.push_level();
+        }
+
+        let mut spliced = Vec::new();
+        let mut option_sentinel = Some(other.entry.as_mut());
+        while let Some(sentinel) = option_sentinel.take() {
+            spliced.push((sentinel.right_mut().take(), sentinel.delta()));
+            if let Node::Sentinel { down, .. } = sentinel {
+                option_sentinel = down.as_deref_mut();
+            }
+        }
+
+        let mut node = self.entry.as_mut();
+        for (right, delta) in spliced {
+            while node.right().is_some() {
+                node = node.right_mut().as_mut().unwrap();
+            }
+            *node.right_mut() = right;
+            node.relink_right();
+            if let Some(last_delta) = node.delta_mut() {
+                *last_delta += delta - 1;
+            }
+            node = match node {
+                Node::Sentinel { down: Some(next_node), .. } => next_node,
+                Node::Index { down: raw_node, .. } => unsafe { raw_node.as_mut() },
+                _ => break,
+            };
+        }
+
+        self.tail = other.tail.take();
+        self.size += other.size;
+        other.size = 0;
+        *other.entry = Node::Sentinel { right: None, down: None, delta: 1 };
+    }
+
+    /// Adds empty levels on top of the tower until it has at least `levels` levels, counting the
+    /// content level.
+    ///
+    /// Operations that rebuild the tower, such as `retune`, drop levels that end up empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..10).collect();
+    /// list.reserve_levels(10);
+    /// assert!(list.height() >= 10);
+    /// ```
+    pub fn reserve_levels(&mut self, levels: usize) {
+        for _ in self.height()..levels {
+            self.push_level();
+        }
+    }
+
+    /// Drops the topmost index levels until at most `keep` levels remain, counting the content
+    /// level.
+    ///
+    /// The remaining levels are the densest ones, so `iter` is unaffected while `get` has to walk
+    /// further along them. Later inserts may grow the tower again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..1000).collect();
+    /// list.flatten_levels(2);
+    /// assert!(list.height() <= 2);
+    /// assert_eq!(list.get(500), Some(&500));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keep == 0`.
+    pub fn flatten_levels(&mut self, keep: usize) {
+        if keep == 0 {
+            panic!("number of levels should be positive");
+        }
+        self.finger.set(None);
+        let height = self.height();
+        for _ in keep..height {
+            let down = match self.entry.as_mut() {
+                Node::Sentinel { down, .. } => down.take().unwrap(),
+                _ => unreachable!(),
+            };
+            let mut top = std::mem::replace(&mut self.entry, down);
+            Node::drop_after(&mut top);
+        }
+        if keep < height {
+            let mut node = self.entry.right_mut().as_deref_mut();
+            while let Some(current) = node {
+                current.set_up(None);
+                node = current.right_mut().as_deref_mut();
+            }
+        }
+    }
+
+    /// Adds an empty level on top of the tower.
+    fn push_level(&mut self) {
+        let delta = self.size + 1;
+        let entry = std::mem::replace(&mut self.entry, Box::new(Node::Sentinel { right: None, down: None, delta }));
+        if let Node::Sentinel { down, .. } = self.entry.as_mut() {
+            *down = Some(entry);
+        }
+    }
+
+    /// Rotates the list in place so that the element at index `mid` becomes the first.
+    ///
+    /// This splits the list at `mid` and appends the front to the back, in `O(log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..5).collect();
+    /// list.rotate_left(2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn rotate_left(&mut self, mid: usize) {
+        let mut back = self.split_off(mid);
+        back.append(self);
+        self.append(&mut back);
+    }
+
+    /// Rotates the list so that the first element equal to `x` becomes the first. Does nothing if
+    /// no element equals `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![3, 1, 2].into_iter().collect();
+    /// list.rotate_to(&1);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn rotate_to(&mut self, x: &T) where T: PartialEq {
+        if let Some(i) = self.position(x) {
+            self.rotate_left(i);
+        }
+    }
+
+    /// Returns the indices of all elements matching `pred`, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3, 4].into_iter().collect();
+    /// assert_eq!(list.indices_where(|x| x % 2 == 0), vec![1, 3]);
+    /// ```
+    pub fn indices_where<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Vec<usize> {
+        let mut indices = Vec::new();
+        for (i, elem) in self.iter().enumerate() {
+            if pred(elem) {
+                indices.push(i);
+            }
+        }
+        indices
+    }
+
+    /// Returns the index of the first element equal to `x`, or `None` if there is none.
+    pub fn position(&self, x: &T) -> Option<usize> where T: PartialEq {
+        self.iter().position(|elem| elem == x)
+    }
+
+    /// Splits the list before the first element matching `pred` and returns the tail, which starts
+    /// with the match. Returns `None` and leaves the list unchanged if nothing matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3, 4].into_iter().collect();
+    /// let tail = list.split_at_first(|x| x % 2 == 0).unwrap();
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1]);
+    /// assert_eq!(tail.into_iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// ```
+    pub fn split_at_first<P: FnMut(&T) -> bool>(&mut self, pred: P) -> Option<Self> {
+        let at = self.iter().position(pred)?;
+        Some(self.split_off(at))
+    }
+
+    /// Removes the first `at` elements and returns them as a new list.
+    ///
+    /// This list keeps its configuration, RNG and metrics; the returned list is configured like
+    /// the one [`split_off`](Self::split_off) returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn take_front(&mut self, at: usize) -> Self {
+        let mut front = self.split_off(at);
+        self.swap_elements(&mut front);
+        front
+    }
+
+    /// Swaps the elements, with their towers, between two lists. Everything else stays with
+    /// each list.
+    fn swap_elements(&mut self, other: &mut Self) {
+        std::mem::swap(&mut self.entry, &mut other.entry);
+        std::mem::swap(&mut self.size, &mut other.size);
+        std::mem::swap(&mut self.tail, &mut other.tail);
+        self.finger.set(None);
+        other.finger.set(None);
+    }
+
+    /// Splits the list into `parts` lists of consecutive elements, in order.
+    ///
+    /// The lengths differ by at most one, with the longer lists first; parts beyond the length of
+    /// the list are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..10).collect();
+    /// let lens: Vec<_> = list.split_into(3).iter().map(|part| part.len()).collect();
+    /// assert_eq!(lens, vec![4, 3, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is 0.
+    pub fn split_into(mut self, parts: usize) -> Vec<Self> {
+        if parts == 0 {
+            panic!("number of parts should be positive");
+        }
+        let (len, extra) = (self.size / parts, self.size % parts);
+        let mut lists = Vec::with_capacity(parts);
+        for k in (1..parts).rev() {
+            lists.push(self.split_off(k * len + k.min(extra)));
+        }
+        lists.push(self);
+        lists.reverse();
+        lists
+    }
+
+    /// Divides the indices `[0, len)` into `chunks` consecutive ranges, sized like the parts of
+    /// [`split_into`](Self::split_into), without touching the list.
+    ///
+    /// Each range can be handed to a separate reader, e.g. one starting from `cursor_read`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..10).collect();
+    /// assert_eq!(list.index_bounds(3), vec![0..4, 4..7, 7..10]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunks` is 0.
+    pub fn index_bounds(&self, chunks: usize) -> Vec<Range<usize>> {
+        if chunks == 0 {
+            panic!("number of parts should be positive");
+        }
+        let (len, extra) = (self.size / chunks, self.size % chunks);
+        let bound = |k: usize| k * len + k.min(extra);
+        (0..chunks).map(|k| bound(k)..bound(k + 1)).collect()
+    }
+
+    /// Keeps only the first `len` elements, dropping the rest. Does nothing if `len` is not less
+    /// than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..10).collect();
+    /// list.truncate(3);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.size {
+            drop(self.split_off(len));
+        }
+    }
+
+    /// Shifts the elements `n` places towards the back, dropping the last `n` and filling the
+    /// front with copies of `fill`. The length stays the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3, 4].into_iter().collect();
+    /// list.shift_right(2, 0);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 0, 1, 2]);
+    /// ```
+    pub fn shift_right(&mut self, n: usize, fill: T) where T: Clone {
+        let n = n.min(self.size);
+        self.truncate(self.size - n);
+        let mut front = self.new_like();
+        front.append_bulk((0..n).map(|_| fill.clone()));
+        front.append(self);
+        self.append(&mut front);
+    }
+
+    /// Shifts the elements `n` places towards the front, dropping the first `n` and filling the
+    /// back with copies of `fill`. The length stays the same.
+    pub fn shift_left(&mut self, n: usize, fill: T) where T: Clone {
+        let n = n.min(self.size);
+        self.truncate_front(self.size - n);
+        let mut back = self.new_like();
+        back.append_bulk((0..n).map(|_| fill.clone()));
+        self.append(&mut back);
+    }
+
+    /// Keeps only the last `keep_last` elements, dropping the rest. Does nothing if `keep_last`
+    /// is not less than the length of the list.
+    ///
+    /// The front is cut off every level at once, as in `split_off`, rather than popped element by
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..10).collect();
+    /// list.truncate_front(3);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![7, 8, 9]);
+    /// ```
+    pub fn truncate_front(&mut self, keep_last: usize) {
+        if keep_last >= self.size {
+            return;
+        }
+        let mut back = self.split_off(self.size - keep_last);
+        drop(self.split_off(0));
+        self.append(&mut back);
+    }
+
+    /// Removes the elements in `range` and puts a single `value` in their place, at the start of
+    /// the range.
+    ///
+    /// The range is cut out with two splits, so this takes `O(log n)` time plus the time to drop
+    /// the removed elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..7).collect();
+    /// list.replace_range(2..5, 99);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 99, 5, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is after its end, or its end is after `len`.
+    pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, value: T) {
+        let range = self.index_range(range);
+        let mut back = self.split_off(range.end);
+        drop(self.split_off(range.start));
+        self.insert(range.start, value);
+        self.append(&mut back);
+    }
+
+    /// Removes up to `n` elements from the start of the list and returns them as a new list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// for i in 0..5 {
+    ///     list.push_back(i);
+    /// }
+    /// assert_eq!(list.pop_front_n(2).into_iter().collect::<Vec<_>>(), vec![0, 1]);
+    /// assert_eq!(list.pop_front_n(10).len(), 3);
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    pub fn pop_front_n(&mut self, n: usize) -> Self {
+        self.take_front(n.min(self.size))
+    }
+
+    /// Removes up to `n` elements from the end of the list and returns them as a new list.
+    pub fn pop_back_n(&mut self, n: usize) -> Self {
+        self.split_off(self.size - n.min(self.size))
+    }
+
+    /// Inserts an element into a sorted list, keeping it sorted.
+    ///
+    /// The element is placed after any elements equal to it. The position is found by walking
+    /// down the tower, so this takes `O(log n)` comparisons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// list.insert_sorted(3);
+    /// list.insert_sorted(1);
+    /// list.insert_sorted(2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn insert_sorted(&mut self, elem: T) where T: Ord {
+        let i = self.upper_bound(&elem);
+        self.insert(i, elem);
+    }
+
+    /// Inserts an element into a sorted list, keeping it sorted, and then drops the smallest
+    /// elements until at most `k` remain.
+    ///
+    /// Fed a stream of values, the list holds the `k` largest seen so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// for x in vec![5, 1, 8, 3, 9, 2] {
+    ///     list.insert_top_k(x, 3);
+    /// }
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![5, 8, 9]);
+    /// ```
+    pub fn insert_top_k(&mut self, x: T, k: usize) where T: Ord {
+        self.insert_sorted(x);
+        while self.size > k {
+            self.pop_front();
+        }
+    }
+
+    /// Inserts an element into a list sorted by `cmp`, keeping it sorted.
+    ///
+    /// The element is placed after any elements equal to it.
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(&mut self, elem: T, mut cmp: F) {
+        let i = self.partition_point(|x| cmp(x, &elem) != Ordering::Greater);
+        self.insert(i, elem);
+    }
+
+    /// Binary searches a sorted list for `x`.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `x` could
+    /// be inserted to keep the list sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 3, 5].into_iter().collect();
+    /// assert_eq!(list.binary_search(&3), Ok(1));
+    /// assert_eq!(list.binary_search(&4), Err(2));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> where T: Ord {
+        self.binary_search_by(|elem| self.compare(elem, x))
+    }
+
+    /// Binary searches a sorted list with a function returning how each element orders against
+    /// the target, as `slice::binary_search_by` does.
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Result<usize, usize> {
+        let i = self.partition_point(|elem| f(elem) == Ordering::Less);
+        match self.get(i) {
+            Some(elem) if f(elem) == Ordering::Equal => Ok(i),
+            _ => Err(i),
+        }
+    }
+
+    /// Returns the index of the first element that is not less than `x` in a sorted list.
+    pub fn lower_bound(&self, x: &T) -> usize where T: Ord {
+        self.lower_bound_by(|elem| self.compare(elem, x))
+    }
+
+    /// Returns the index of the first element for which `f` does not return `Less`.
+    pub fn lower_bound_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> usize {
+        self.partition_point(|elem| f(elem) == Ordering::Less)
+    }
+
+    /// Returns the index of the first element that is greater than `x` in a sorted list.
+    pub fn upper_bound(&self, x: &T) -> usize where T: Ord {
+        self.upper_bound_by(|elem| self.compare(elem, x))
+    }
+
+    /// Returns the index of the first element for which `f` returns `Greater`.
+    pub fn upper_bound_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> usize {
+        self.partition_point(|elem| f(elem) != Ordering::Greater)
+    }
+
+    /// Returns the number of elements equal to `x` in a sorted list, in `O(log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 3, 3, 3, 5].into_iter().collect();
+    /// assert_eq!(list.count_sorted(&3), 3);
+    /// assert_eq!(list.count_sorted(&4), 0);
+    /// ```
+    pub fn count_sorted(&self, x: &T) -> usize where T: Ord {
+        self.upper_bound(x) - self.lower_bound(x)
+    }
+
+    /// Returns the element of a sorted list closest to `target`, preferring the smaller one on a
+    /// tie, or `None` if the list is empty.
+    ///
+    /// The list must be sorted by `Ord`; a custom comparator is ignored, since distances are taken
+    /// with `Sub`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 5, 9].into_iter().collect();
+    /// assert_eq!(list.nearest(&6), Some(&5));
+    /// assert_eq!(list.nearest(&20), Some(&9));
+    /// ```
+    pub fn nearest(&self, target: &T) -> Option<&T> where T: Ord + Sub<Output = T> + Copy {
+        let i = self.partition_point(|elem| elem < target);
+        let before = if i > 0 { self.get(i - 1) } else { None };
+        match (before, self.get(i)) {
+            (Some(a), Some(b)) => Some(if *target - *a <= *b - *target { a } else { b }),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Compares two elements with the list's comparator, falling back to `Ord`.
+    fn compare(&self, a: &T, b: &T) -> Ordering where T: Ord {
+        match &self.comparator {
+            Some(cmp) => cmp(a, b),
+            None => a.cmp(b),
+        }
+    }
+
+    /// Inserts an element into a list sorted by its comparator, keeping it sorted, as
+    /// `insert_sorted` does but without requiring `T: Ord`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list was not created with `new_with_comparator`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new_with_comparator(|a: &f64, b: &f64| a.partial_cmp(b).unwrap());
+    /// list.insert_sorted_with_comparator(2.5);
+    /// list.insert_sorted_with_comparator(-1.0);
+    /// assert_eq!(list.binary_search_with_comparator(&2.5), Ok(1));
+    /// ```
+    pub fn insert_sorted_with_comparator(&mut self, elem: T) {
+        let i = self.upper_bound_with_comparator(&elem);
+        self.insert(i, elem);
+    }
+
+    /// Binary searches a list sorted by its comparator for `x`, as `binary_search` does but without
+    /// requiring `T: Ord`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list was not created with `new_with_comparator`.
+    pub fn binary_search_with_comparator(&self, x: &T) -> Result<usize, usize> {
+        let cmp = self.stored_comparator();
+        self.binary_search_by(|elem| cmp(elem, x))
+    }
+
+    /// Returns the index of the first element that is not less than `x` by the list's comparator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list was not created with `new_with_comparator`.
+    pub fn lower_bound_with_comparator(&self, x: &T) -> usize {
+        let cmp = self.stored_comparator();
+        self.lower_bound_by(|elem| cmp(elem, x))
+    }
+
+    /// Returns the index of the first element that is greater than `x` by the list's comparator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list was not created with `new_with_comparator`.
+    pub fn upper_bound_with_comparator(&self, x: &T) -> usize {
+        let cmp = self.stored_comparator();
+        self.upper_bound_by(|elem| cmp(elem, x))
+    }
+
+    /// Returns the list's comparator, for the sorted operations that do not fall back to `Ord`.
+    fn stored_comparator(&self) -> &Comparator<T> {
+        match &self.comparator {
+            Some(cmp) => cmp.as_ref(),
+            None => panic!("list has no comparator; create it with `new_with_comparator`"),
+        }
+    }
+
+    /// Removes every element equal to one before it, keeping the first occurrence of each value.
+    ///
+    /// All duplicates are unlinked in a single `O(n)` sweep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 1, 3, 2, 4].into_iter().collect();
+    /// list.unique();
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn unique(&mut self) where T: Eq + Hash {
+        let mut seen = HashSet::new();
+        let marked: Vec<bool> = self.iter().map(|elem| !seen.insert(elem)).collect();
+        self.remove_marked(&marked, drop);
+    }
+
+    /// Merges pairs of adjacent elements with `f`.
+    ///
+    /// `f` is called with an element and the one after it. If it returns `Some(merged)`, `merged`
+    /// replaces the pair; otherwise the walk moves on by one element. A merged element takes no part
+    /// in further merges, so `[1, 1, 1]` coalesced by summing equal values becomes `[2, 1]`. All
+    /// merged elements are unlinked in a single sweep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1, 1, 2, 3, 3].into_iter().collect();
+    /// list.coalesce(|a, b| if a == b { Some(a + b) } else { None });
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 2, 6]);
+    /// ```
+    pub fn coalesce<F: FnMut(&T, &T) -> Option<T>>(&mut self, mut f: F) {
+        let mut marked = Vec::with_capacity(self.size);
+        let mut prev: Option<&mut T> = None;
+        for elem in self.iter_mut() {
+            match prev.take().map(|prev| (f(prev, elem), prev)) {
+                Some((Some(merged), prev)) => {
+                    *prev = merged;
+                    marked.push(true);
+                },
+                _ => {
+                    prev = Some(elem);
+                    marked.push(false);
+                },
+            }
+        }
+        self.remove_marked(&marked, drop);
+    }
+
+    /// Removes every element within `tolerance` of the element just before it, so each run of
+    /// near-equal elements is reduced to its first one.
+    ///
+    /// Elements are compared with their immediate neighbour in the original list, so a slowly
+    /// drifting sequence forms a single run however far it drifts. Removals are unlinked in a single
+    /// sweep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1.0, 1.05, 2.0].into_iter().collect();
+    /// list.coalesce_within(0.1);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+    /// ```
+    pub fn coalesce_within(&mut self, tolerance: f64) where T: Into<f64> + Copy {
+        let mut marked = Vec::with_capacity(self.size);
+        let mut prev: Option<f64> = None;
+        for elem in self.iter() {
+            let cur = (*elem).into();
+            marked.push(matches!(prev, Some(prev) if (cur - prev).abs() <= tolerance));
+            prev = Some(cur);
+        }
+        self.remove_marked(&marked, drop);
+    }
+
+    /// Removes all elements matching `pred` and returns them as a new list, in their original order.
+    ///
+    /// This is the complement of retaining the non-matching elements: both lists are produced in a
+    /// single `O(n)` sweep rather than by removing elements one at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..6).collect();
+    /// let evens = list.take_matching(|x| x % 2 == 0);
+    /// assert_eq!(evens.into_iter().collect::<Vec<_>>(), vec![0, 2, 4]);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 3, 5]);
+    /// ```
+    pub fn take_matching<P: FnMut(&T) -> bool>(&mut self, pred: P) -> Self {
+        let marked: Vec<bool> = self.iter().map(pred).collect();
+        let mut taken = Vec::new();
+        self.remove_marked(&marked, |elem| taken.push(elem));
+        let mut other = self.new_like();
+        other.append_bulk(taken.into_iter());
+        other
+    }
+
+    /// Reorders the list so that all elements matching `pred` come first, keeping the relative
+    /// order within each group, and returns the index of the first non-matching element.
+    ///
+    /// The matching elements are taken out in one sweep and the rest are spliced on after them.
+    /// The list keeps its configuration, RNG and metrics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3, 4].into_iter().collect();
+    /// assert_eq!(list.stable_partition(|x| x % 2 == 0), 2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 4, 1, 3]);
+    /// ```
+    pub fn stable_partition<P: FnMut(&T) -> bool>(&mut self, pred: P) -> usize {
+        let mut matching = self.take_matching(pred);
+        let split = matching.len();
+        matching.append(self);
+        self.swap_elements(&mut matching);
+        split
+    }
+
+    /// Removes the elements at the indices marked `true` in one sweep over every level, passing
+    /// the removed elements to `sink` in order.
+    ///
+    /// Index nodes above a removed element are unlinked along with it, and the spans of the
+    /// surviving nodes are recomputed from the number of removals before each position.
+    fn remove_marked<F: FnMut(T)>(&mut self, marked: &[bool], mut sink: F) {
+        let size = self.size;
+        let mut removed_before = Vec::with_capacity(size + 2);
+        let mut removed = 0;
+        removed_before.push(0);
+        for &is_marked in marked {
+            removed_before.push(removed);
+            if is_marked {
+                removed += 1;
+            }
+        }
+        removed_before.push(removed);
+        if removed == 0 {
+            return;
+        }
+        self.finger.set(None);
+
+        let mut sentinel = self.entry.as_mut();
+        loop {
+            let mut node = &mut *sentinel;
+            let mut pos = 0;
+            loop {
+                let mut next_pos = pos + node.delta();
+                while next_pos <= size && marked[next_pos - 1] {
+                    let mut removed_node = node.right_mut().take().unwrap();
+                    *node.right_mut() = removed_node.right_mut().take();
+                    next_pos += removed_node.delta();
+                    if let Node::Content { elem, .. } = *removed_node {
+                        sink(elem);
+                    }
+                }
+                if let Some(delta) = node.delta_mut() {
+                    *delta = (next_pos - removed_before[next_pos]) - (pos - removed_before[pos]);
+                }
+                node.relink_right();
+                if node.right().is_none() {
+                    break;
+                }
+                node = node.right_mut().as_mut().unwrap();
+                pos = next_pos;
+            }
+            let last = match node {
+                Node::Content { .. } => Some(NonNull::from(node)),
+                _ => None,
+            };
+            sentinel = match sentinel {
+                Node::Sentinel { down: Some(next_sentinel), .. } => next_sentinel,
+                _ => {
+                    self.tail = last;
+                    break;
+                },
+            };
+        }
+        self.size -= removed;
+    }
+
+    /// Returns the number of leading elements for which `pred` holds, assuming the list is
+    /// partitioned by it (all `true` before all `false`).
+    fn partition_point<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        let mut node = self.entry.as_ref();
+        let mut pos = 0;
+        loop {
+            while let Some(next_node) = node.right() {
+                match next_node.down_elem() {
+                    Some(elem) if pred(elem) => {
+                        pos += node.delta();
+                        node = next_node;
+                    },
+                    _ => break,
+                }
+            }
+            node = match node {
+                Node::Sentinel { down: Some(next_node), .. } => next_node,
+                Node::Index { down: raw_node, .. } => unsafe { raw_node.as_ref() },
+                _ => return pos,
+            };
+        }
+    }
+
+    /// Pairs up the elements of two lists, stopping at the end of the shorter one.
+    ///
+    /// The pairs are laid out in a single bulk build, in `O(n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// let other: skip_linked_list::SkipLinkedList<_> = vec!['a', 'b'].into_iter().collect();
+    /// assert_eq!(list.zip(other).into_iter().collect::<Vec<_>>(), vec![(1, 'a'), (2, 'b')]);
+    /// ```
+    pub fn zip<U>(self, other: SkipLinkedList<U>) -> SkipLinkedList<(T, U)> {
+        let mut list = self.new_mapped();
+        list.append_bulk(self.into_iter().zip(other));
+        list
+    }
+
+    /// Builds a new list from the intermediate results of running `f` over the elements with the
+    /// state `init`, like `Iterator::scan` without early termination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![3, 1, 4, 1, 5].into_iter().collect();
+    /// let maxima = list.scan_into(i32::MIN, |max, elem| {
+    ///     *max = (*max).max(*elem);
+    ///     *max
+    /// });
+    /// assert_eq!(maxima.into_iter().collect::<Vec<_>>(), vec![3, 3, 4, 4, 5]);
+    /// ```
+    pub fn scan_into<S, U, F>(&self, init: S, mut f: F) -> SkipLinkedList<U> where F: FnMut(&mut S, &T) -> U {
+        let mut state = init;
+        let mut list = self.new_mapped();
+        list.append_bulk(self.iter().map(|elem| f(&mut state, elem)));
+        list
+    }
+
+    /// Counts the occurrences of each value and returns `(value, count)` pairs ordered by
+    /// descending count, with ties in order of first appearance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = "abacab".chars().collect();
+    /// let counts: Vec<_> = list.by_frequency().into_iter().collect();
+    /// assert_eq!(counts, vec![('a', 3), ('b', 2), ('c', 1)]);
+    /// ```
+    pub fn by_frequency(self) -> SkipLinkedList<(T, usize)> where T: Eq + Hash {
+        let mut list = self.new_mapped();
+        let mut slots = HashMap::new();
+        let mut counts = Vec::new();
+        for elem in self {
+            let slot = *slots.entry(elem).or_insert_with(|| {
+                counts.push(0);
+                counts.len() - 1
+            });
+            counts[slot] += 1;
+        }
+        let mut pairs: Vec<(T, usize, usize)> = slots.into_iter().map(|(elem, slot)| (elem, counts[slot], slot)).collect();
+        pairs.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+        list.append_bulk(pairs.into_iter().map(|(elem, count, _)| (elem, count)));
+        list
+    }
+
+    /// Collapses each run of consecutive equal elements into a `(value, run_length)` pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = "aabccc".chars().collect();
+    /// let runs: Vec<_> = list.run_length_encode().into_iter().collect();
+    /// assert_eq!(runs, vec![('a', 2), ('b', 1), ('c', 3)]);
+    /// ```
+    pub fn run_length_encode(&self) -> SkipLinkedList<(T, usize)> where T: PartialEq + Clone {
+        let mut list = self.new_mapped();
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        for elem in self.iter() {
+            match runs.last_mut() {
+                Some((value, count)) if value == elem => *count += 1,
+                _ => runs.push((elem.clone(), 1)),
+            }
+        }
+        list.append_bulk(runs.into_iter());
+        list
+    }
+
+    /// Expands `(value, count)` pairs into a list with each value repeated `count` times. This is
+    /// the inverse of [`run_length_encode`](Self::run_length_encode).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_linked_list::SkipLinkedList;
+    ///
+    /// let pairs: SkipLinkedList<_> = vec![('a', 2), ('b', 1)].into_iter().collect();
+    /// let list = SkipLinkedList::run_length_decode(pairs);
+    /// assert_eq!(list.into_iter().collect::<String>(), "aab");
+    /// ```
+    pub fn run_length_decode(pairs: SkipLinkedList<(T, usize)>) -> Self where T: Clone {
+        let mut list = pairs.new_mapped();
+        list.append_bulk(pairs.into_iter().flat_map(|(value, count)| (0..count).map(move |_| value.clone())));
+        list
+    }
+
+    /// Applies `f` to every window of `size` consecutive elements and collects the results into a
+    /// new list of length `len - size + 1`, or an empty list if `size > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3, 4].into_iter().collect();
+    /// let sums = list.window_reduce(2, |window| window.iter().copied().sum::<i32>());
+    /// assert_eq!(sums.into_iter().collect::<Vec<_>>(), vec![3, 5, 7]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn window_reduce<U, F>(&self, size: usize, f: F) -> SkipLinkedList<U> where F: FnMut(&[&T]) -> U {
+        if size == 0 {
+            panic!("window size should be positive");
+        }
+        let elems: Vec<&T> = self.iter().collect();
+        let mut list = self.new_mapped();
+        list.append_bulk(elems.windows(size).map(f));
+        list
+    }
+
+    /// Returns the `k`-th smallest element, counting from 0, or `None` if `k >= len`.
+    ///
+    /// This runs a quickselect over references to the elements, in `O(n)` expected time and
+    /// `O(n)` extra space, and leaves the list untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![5, 1, 4, 2, 3].into_iter().collect();
+    /// assert_eq!(list.kth_smallest(1), Some(&2));
+    /// assert_eq!(list.kth_smallest(5), None);
+    /// ```
+    pub fn kth_smallest(&self, k: usize) -> Option<&T> where T: Ord {
+        if k >= self.size {
+            return None;
+        }
+        let mut elems: Vec<&T> = self.iter().collect();
+        let (_, kth, _) = elems.select_nth_unstable(k);
+        Some(*kth)
+    }
+
+    /// Returns the number of pairs of indices `i < j` whose elements are out of order, that is,
+    /// with the element at `i` greater than the one at `j`.
+    ///
+    /// The pairs are counted by a bottom-up merge sort over references to the elements, in
+    /// `O(n log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![3, 1, 2].into_iter().collect();
+    /// assert_eq!(list.count_inversions(), 2);
+    /// ```
+    pub fn count_inversions(&self) -> usize where T: Ord {
+        let mut elems: Vec<&T> = self.iter().collect();
+        let mut merged = Vec::with_capacity(elems.len());
+        let mut inversions = 0;
+        let mut width = 1;
+        while width < elems.len() {
+            for start in (0..elems.len()).step_by(2 * width) {
+                let mid = (start + width).min(elems.len());
+                let end = (start + 2 * width).min(elems.len());
+                let (mut i, mut j) = (start, mid);
+                while i < mid && j < end {
+                    if elems[j] < elems[i] {
+                        // Everything left in the first half is greater than elems[j].
+                        inversions += mid - i;
+                        merged.push(elems[j]);
+                        j += 1;
+                    } else {
+                        merged.push(elems[i]);
+                        i += 1;
+                    }
+                }
+                merged.extend_from_slice(&elems[i..mid]);
+                merged.extend_from_slice(&elems[j..end]);
+            }
+            std::mem::swap(&mut elems, &mut merged);
+            merged.clear();
+            width *= 2;
+        }
+        inversions
+    }
+
+    /// Returns `k` distinct elements chosen at random with the list's RNG, or all of them, in
+    /// order, if `k >= len`. The chosen elements come in no particular order.
+    ///
+    /// This is reservoir sampling over a single walk of the content level, in `O(n)` time. Lists
+    /// created with [`with_seed`](Self::with_seed) sample deterministically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// let sample = list.sample(5);
+    /// assert_eq!(sample.len(), 5);
+    /// assert!(sample.iter().all(|x| **x < 100));
+    /// ```
+    pub fn sample(&self, k: usize) -> Vec<&T> {
+        let mut iter = self.iter();
+        let mut reservoir: Vec<&T> = iter.by_ref().take(k).collect();
+        let mut rng = self.rng.borrow_mut();
+        for (i, elem) in iter.enumerate() {
+            let j = rng.gen_range(0, k + i + 1);
+            if j < k {
+                reservoir[j] = elem;
+            }
+        }
+        reservoir
+    }
+
+    /// Returns the index range of the longest strictly increasing run of consecutive elements.
+    ///
+    /// Ties are broken in favor of the earliest run; an empty list yields `0..0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 1, 2, 3, 1].into_iter().collect();
+    /// assert_eq!(list.longest_increasing_run(), 2..5);
+    /// ```
+    pub fn longest_increasing_run(&self) -> Range<usize> where T: PartialOrd {
+        let mut longest = 0..0;
+        let mut start = 0;
+        let mut prev = None;
+        for (i, elem) in self.iter().enumerate() {
+            match prev {
+                Some(prev) if prev < elem => (),
+                _ => start = i,
+            }
+            if i + 1 - start > longest.len() {
+                longest = start..i + 1;
+            }
+            prev = Some(elem);
+        }
+        longest
+    }
+
+    /// Returns the differences between adjacent elements, `[a[1] - a[0], a[2] - a[1], ...]`.
+    ///
+    /// The result has `len - 1` elements, and is empty if the list has fewer than two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 3, 6, 10].into_iter().collect();
+    /// assert_eq!(list.deltas(), vec![2, 3, 4]);
+    /// ```
+    pub fn deltas(&self) -> Vec<T> where T: Sub<Output = T> + Copy {
+        self.transitions().map(|(a, b)| *b - *a).collect()
+    }
+
+    /// Returns an iterator over each pair of adjacent elements, `(a[0], a[1]), (a[1], a[2]), ...`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.transitions().collect::<Vec<_>>(), vec![(&1, &2), (&2, &3)]);
+    /// ```
+    pub fn transitions(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.iter().zip(self.iter().skip(1))
+    }
+
+    /// Returns `true` if all differences between adjacent elements are equal. Lists with fewer
+    /// than two elements are trivially arithmetic.
+    ///
+    /// Each difference is compared as a direction and a magnitude, subtracting the smaller element
+    /// from the larger, so decreasing lists of unsigned numbers don't overflow. Adjacent elements
+    /// that can't be ordered, like a NaN, make the list not arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![2, 4, 6, 8].into_iter().collect();
+    /// assert!(list.is_arithmetic());
+    /// let list: skip_linked_list::SkipLinkedList<u32> = vec![9, 6, 3].into_iter().collect();
+    /// assert!(list.is_arithmetic());
+    /// ```
+    pub fn is_arithmetic(&self) -> bool where T: Sub<Output = T> + PartialOrd + Copy {
+        let mut steps = self.transitions().map(|(a, b)| match a.partial_cmp(b)? {
+            Ordering::Greater => Some((Ordering::Greater, *a - *b)),
+            order => Some((order, *b - *a)),
+        });
+        match steps.next() {
+            Some(Some(first)) => steps.all(|step| step == Some(first)),
+            Some(None) => false,
+            None => true,
+        }
+    }
+
+    /// Returns `true` if the elements read the same forwards and backwards.
+    ///
+    /// This walks the content level from both ends until they meet, in `O(n)` time and `O(1)`
+    /// extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 1].into_iter().collect();
+    /// assert!(list.is_palindrome());
+    /// ```
+    pub fn is_palindrome(&self) -> bool where T: PartialEq {
+        self.iter().zip(self.iter().rev()).take(self.size / 2).all(|(a, b)| a == b)
+    }
+
+    /// Returns a hash of the length and elements of the list, in order, that does not depend on
+    /// the shape of the tower.
+    ///
+    /// The hash uses FNV-1a rather than the standard library's randomly keyed hasher, so equal
+    /// lists give the same checksum across runs.
+    ///
+    /// The length, and any integers the elements hash, are fed to it as little-endian bytes, with
+    /// `usize` and `isize` widened to 64 bits. Checksums of integers, `char`s, `bool`s, strings,
+    /// and tuples, arrays and vectors of them are therefore the same on every platform; other
+    /// types are portable if their `Hash` implementations only hash such values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..3).collect();
+    /// let other: skip_linked_list::SkipLinkedList<_> = (0..3).collect();
+    /// assert_eq!(list.checksum(), other.checksum());
+    /// ```
+    pub fn checksum(&self) -> u64 where T: Hash {
+        let mut hasher = Fnv1a::default();
+        hasher.write(&(self.size as u64).to_le_bytes());
+        for elem in self.iter() {
+            elem.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Returns `true` if `iter` yields exactly the elements of the list, in order.
+    ///
+    /// The comparison stops at the first mismatch, without collecting `iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..3).collect();
+    /// assert!(list.eq_iter(0..3));
+    /// assert!(!list.eq_iter(0..2));
+    /// ```
+    pub fn eq_iter<I: IntoIterator<Item = T>>(&self, iter: I) -> bool where T: PartialEq {
+        let mut iter = iter.into_iter();
+        self.iter().all(|elem| iter.next().as_ref() == Some(elem)) && iter.next().is_none()
+    }
+
+    /// Returns the index of the first element that differs from `other`, the length of the shorter
+    /// list if one is a prefix of the other, or `None` if the lists are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// let other: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 4].into_iter().collect();
+    /// assert_eq!(list.first_diff(&other), Some(2));
+    /// ```
+    pub fn first_diff(&self, other: &SkipLinkedList<T>) -> Option<usize> where T: PartialEq {
+        match self.iter().zip(other.iter()).position(|(a, b)| a != b) {
+            Some(i) => Some(i),
+            None if self.size != other.size => Some(self.size.min(other.size)),
+            None => None,
+        }
+    }
+
+    /// Returns an edit script that turns `self` into `other`, keeping a longest common subsequence
+    /// of the two lists in place.
+    ///
+    /// This is Myers' algorithm: with `n` and `m` elements and `d` edits in the script, it takes
+    /// `O((n + m) * d)` time and `O(n + m + d * d)` space, so similar lists are compared quickly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_linked_list::{ops::Edit, SkipLinkedList};
+    ///
+    /// let list: SkipLinkedList<_> = "abc".chars().collect();
+    /// let other: SkipLinkedList<_> = "axc".chars().collect();
+    /// assert_eq!(list.diff(&other), vec![Edit::Keep, Edit::Remove(1), Edit::Insert(1, 'x'), Edit::Keep]);
+    /// ```
+    pub fn diff(&self, other: &SkipLinkedList<T>) -> Vec<Edit<T>> where T: PartialEq + Clone {
+        let a: Vec<&T> = self.iter().collect();
+        let b: Vec<&T> = other.iter().collect();
+        let (n, m) = (a.len() as isize, b.len() as isize);
+
+        // ends[k] is the furthest index into `a` reached on diagonal k = x - y, where x and y index
+        // `a` and `b`. Before round d, the diagonals -d - 1..=d + 1 are kept to trace the path back.
+        let offset = n + m + 1;
+        let mut ends = vec![0; 2 * offset as usize + 1];
+        let end_at = |ends: &[isize], k: isize| ends[(k + offset) as usize];
+        let mut trace = Vec::new();
+        'search: for d in 0..=n + m {
+            trace.push(ends[(offset - d - 1) as usize..=(offset + d + 1) as usize].to_vec());
+            for k in (-d..=d).step_by(2) {
+                let mut x = if k == -d || (k != d && end_at(&ends, k - 1) < end_at(&ends, k + 1)) {
+                    end_at(&ends, k + 1)
+                } else {
+                    end_at(&ends, k - 1) + 1
+                };
+                let mut y = x - k;
+                while x < n && y < m && a[x as usize] == b[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                ends[(k + offset) as usize] = x;
+                if x >= n && y >= m {
+                    break 'search;
+                }
+            }
+        }
+
+        // Walks back from the end, one edit per round. The steps hold the index into `b` to insert
+        // from in place of the value; positions in the list are filled in on the way forward.
+        let mut steps = Vec::new();
+        let (mut x, mut y) = (n, m);
+        for (d, ends) in trace.iter().enumerate().rev() {
+            let d = d as isize;
+            let end_at = |k: isize| ends[(k + d + 1) as usize];
+            let k = x - y;
+            let (prev_x, prev_y) = if d == 0 {
+                (0, 0)
+            } else {
+                let prev_k = if k == -d || (k != d && end_at(k - 1) < end_at(k + 1)) { k + 1 } else { k - 1 };
+                (end_at(prev_k), end_at(prev_k) - prev_k)
+            };
+            while x > prev_x && y > prev_y {
+                steps.push(Edit::Keep);
+                x -= 1;
+                y -= 1;
+            }
+            if d > 0 {
+                steps.push(if x == prev_x { Edit::Insert(0, prev_y as usize) } else { Edit::Remove(0) });
+            }
+            x = prev_x;
+            y = prev_y;
+        }
+
+        let mut pos = 0;
+        steps.into_iter().rev().map(|step| match step {
+            Edit::Keep => {
+                pos += 1;
+                Edit::Keep
+            },
+            Edit::Remove(_) => Edit::Remove(pos),
+            Edit::Insert(_, j) => {
+                pos += 1;
+                Edit::Insert(pos - 1, b[j].clone())
+            },
+        }).collect()
+    }
+
+    /// Applies the edits in order, as produced by [`diff`](Self::diff).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = "abc".chars().collect();
+    /// let other: skip_linked_list::SkipLinkedList<_> = "xbcd".chars().collect();
+    /// list.apply_edits(&list.diff(&other));
+    /// assert_eq!(list.into_iter().collect::<String>(), "xbcd");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if an edit's index is out of bounds when it is applied.
+    pub fn apply_edits(&mut self, edits: &[Edit<T>]) where T: Clone {
+        for edit in edits {
+            match edit {
+                Edit::Insert(i, elem) => self.insert(*i, elem.clone()),
+                Edit::Remove(i) => {
+                    self.remove(*i);
+                },
+                Edit::Keep => (),
+            }
+        }
+    }
+
+    /// Returns `true` if both lists have the same length and `key` maps the elements at each index
+    /// to equal keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+    /// let other: skip_linked_list::SkipLinkedList<_> = vec![(1, 'x'), (2, 'y')].into_iter().collect();
+    /// assert!(list.eq_by_key(&other, |pair| pair.0));
+    /// ```
+    pub fn eq_by_key<K: PartialEq, F: FnMut(&T) -> K>(&self, other: &SkipLinkedList<T>, mut key: F) -> bool {
+        self.size == other.size && self.iter().zip(other.iter()).all(|(a, b)| key(a) == key(b))
+    }
+
+    /// Returns the current index of the element stored at `ptr`, or `None` if no element of the
+    /// list lives there.
+    ///
+    /// Elements don't move in memory while they are in the list, except when
+    /// [`swap_adjacent`](Self::swap_adjacent) exchanges two of them, so this finds an element by
+    /// identity even after inserts and removes have shifted its index. After a swap, `ptr` refers
+    /// to whichever value now sits where it points; use a handle from `push_front_handle` to
+    /// follow a value instead. This takes `O(n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..5).collect();
+    /// let ptr: *const i32 = list.get(3).unwrap();
+    /// list.push_front(-1);
+    /// assert_eq!(list.index_of_ptr(ptr), Some(4));
+    ///
+    /// list.swap_adjacent(3);
+    /// assert_eq!(list.index_of_ptr(ptr), Some(4));
+    /// assert_eq!(list.get(4), Some(&2));
+    /// ```
+    pub fn index_of_ptr(&self, ptr: *const T) -> Option<usize> {
+        self.iter().position(|elem| std::ptr::eq(elem, ptr))
+    }
+
+    /// Returns `true` if the elements equal those of `other` read backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// let other: skip_linked_list::SkipLinkedList<_> = vec![3, 2, 1].into_iter().collect();
+    /// assert!(list.is_reverse_of(&other));
+    /// ```
+    pub fn is_reverse_of(&self, other: &SkipLinkedList<T>) -> bool where T: PartialEq {
+        self.size == other.size && self.iter().zip(other.iter().rev()).all(|(a, b)| a == b)
+    }
+
+    /// Clones all elements, in order, into a contiguous boxed slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(&*list.to_boxed_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn to_boxed_slice(&self) -> Box<[T]> where T: Clone {
+        let mut vec = Vec::with_capacity(self.size);
+        vec.extend(self.iter().cloned());
+        vec.into_boxed_slice()
+    }
+
+    /// Clears `out` and fills it with clones of the elements, in order, reusing its allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..3).collect();
+    /// let mut out = vec![7; 10];
+    /// list.clone_into_vec(&mut out);
+    /// assert_eq!(out, vec![0, 1, 2]);
+    /// ```
+    pub fn clone_into_vec(&self, out: &mut Vec<T>) where T: Clone {
+        out.clear();
+        out.extend(self.iter().cloned());
+    }
+
+    /// Returns `true` if the elements appear in `other` in the same order, not necessarily next
+    /// to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 3].into_iter().collect();
+    /// let other: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert!(list.is_subsequence_of(&other));
+    /// ```
+    pub fn is_subsequence_of(&self, other: &SkipLinkedList<T>) -> bool where T: PartialEq {
+        let mut others = other.iter();
+        self.iter().all(|elem| others.any(|other_elem| other_elem == elem))
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator is double-ended; iterating from the back follows the back links of the
+    /// content level and takes `O(1)` per element.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.bottom().right().map(|node| NonNull::from(node.as_ref())),
+            tail: self.tail,
+            len: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// Consumes the list into an iterator that yields the elements from the back to the front.
+    ///
+    /// Each element is taken with `pop_back`, which descends the tower, so this takes
+    /// `O(log n)` per element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.into_iter_rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn into_iter_rev(self) -> IntoIterRev<T> {
+        IntoIterRev(self)
+    }
+
+    /// Copies the elements into an immutable [`Snapshot`] that does not borrow the list.
+    ///
+    /// Taking a snapshot clones every element, in `O(n)` time. Cloning the snapshot afterwards is
+    /// `O(1)`, and it can be shared with other threads when `T` is `Send + Sync`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..3).collect();
+    /// let snapshot = list.snapshot();
+    /// list.push_back(3);
+    /// assert_eq!(snapshot.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+    /// ```
+    pub fn snapshot(&self) -> Snapshot<T> where T: Clone {
+        Snapshot { elems: self.iter().cloned().collect() }
+    }
+
+    /// Moves the elements into a [`FrozenList`], an immutable flat vector with `O(1)` `get`, for a
+    /// phase of heavy reads, in `O(n)` time. `FrozenList::thaw` turns it back into a list with the
+    /// same configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..10).collect();
+    /// let frozen = list.freeze();
+    /// assert_eq!(frozen.get(7), Some(&7));
+    /// ```
+    pub fn freeze(self) -> FrozenList<T> {
+        FrozenList::new(self)
+    }
+
+    /// Returns a read-only cursor positioned at index `start`.
+    ///
+    /// The cursor keeps the node it last visited on every level, so `advance` is `O(1)` and a
+    /// forward `seek` only climbs as high as the distance requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = (0..100).collect();
+    /// let mut cursor = list.cursor_read(10);
+    /// assert_eq!(cursor.current(), Some(&10));
+    /// cursor.advance();
+    /// assert_eq!(cursor.current(), Some(&11));
+    /// cursor.seek(90);
+    /// assert_eq!(cursor.current(), Some(&90));
+    /// ```
+    pub fn cursor_read(&self, start: usize) -> ReadCursor<'_, T> {
+        let mut cursor = ReadCursor {
+            entry: self.entry.as_ref(),
+            path: vec![(0, self.entry.as_ref())],
+            index: 0,
+            len: self.size,
+        };
+        cursor.seek(start);
+        cursor
+    }
+
+    /// Returns an mut iterator over the list.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.bottom_mut().right_mut().as_mut().map(|node| NonNull::from(node.as_mut())),
+            tail: self.tail,
+            len: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator of mutable references from the back of the list to the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (1..4).collect();
+    /// let mut carry = 0;
+    /// for elem in list.iter_mut_rev() {
+    ///     carry += *elem;
+    ///     *elem = carry;
+    /// }
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![6, 5, 3]);
+    /// ```
+    pub fn iter_mut_rev(&mut self) -> impl Iterator<Item = &mut T> {
+        self.iter_mut().rev()
+    }
+
+    /// Applies `f` to each element in the index `range`, in order.
+    ///
+    /// This seeks to the start of the range in `O(log n)` and then walks the content level, so
+    /// elements outside the range are never visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..6).collect();
+    /// list.for_each_mut_range(2..5, |elem| *elem *= 2);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 4, 6, 8, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is after its end, or its end is after `len`.
+    pub fn for_each_mut_range<R: RangeBounds<usize>, F: FnMut(&mut T)>(&mut self, range: R, mut f: F) {
+        let range = self.index_range(range);
+        if range.is_empty() {
+            return;
+        }
+        let mut node = Node::seek_mut(&mut self.entry, range.start + 1);
+        for i in range.clone() {
+            if let Node::Content { elem, .. } = &mut *node {
+                f(elem);
+            }
+            if i + 1 < range.end {
+                node = node.right_mut().as_mut().unwrap();
+            }
+        }
+    }
+
+    /// Returns an iterator over the elements in the index `range`, which must be within bounds.
+    #[cfg(any(test, feature = "rayon"))]
+    fn range_iter(&self, range: Range<usize>) -> Iter<'_, T> {
+        if range.is_empty() {
+            return Iter { head: None, tail: None, len: 0, marker: PhantomData };
+        }
+        Iter {
+            head: Some(NonNull::from(Node::seek(&self.entry, range.start + 1, &mut 0))),
+            tail: Some(NonNull::from(Node::seek(&self.entry, range.end, &mut 0))),
+            len: range.len(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Resolves `range` against the length of the list.
+    fn index_range<R: RangeBounds<usize>>(&self, range: R) -> Range<usize> {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.size,
+        };
+        if start > end {
+            panic!("range start {} should be <= range end {}", start, end);
+        }
+        if end > self.size {
+            panic!("range end {} should be <= len (is {})", end, self.size);
+        }
+        start..end
+    }
+
+    /// Returns an iterator over the content nodes, front to back.
+    ///
+    /// The pointers are only valid while the list is not structurally modified: no element may be
+    /// inserted or removed, and the list may not be dropped, while they are dereferenced.
+    pub(crate) fn content_nodes(&self) -> impl Iterator<Item = NonNull<Node<T>>> + '_ {
+        let first = self.bottom().right().map(|node| NonNull::from(node.as_ref()));
+        std::iter::successors(first, |raw_node| {
+            unsafe { raw_node.as_ref() }.right().map(|node| NonNull::from(node.as_ref()))
+        })
+    }
+
+    fn bottom(&self) -> &Node<T> {
+        let mut node = self.entry.as_ref();
+        while let Node::Sentinel{ down: Some(next_node), .. } = node {
+            node = next_node;
+        }
+        node
+    }
+
+    fn bottom_mut(&mut self) -> &mut Node<T> {
+        let mut node = self.entry.as_mut();
+        while let Node::Sentinel{ down: Some(next_node), .. } = node {
+            node = next_node;
+        }
+        node
+    }
+
+    /// Returns an iterator over the elements of several lists in order, without consuming them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_linked_list::SkipLinkedList;
+    ///
+    /// let a: SkipLinkedList<_> = vec![1, 2].into_iter().collect();
+    /// let b: SkipLinkedList<_> = vec![3].into_iter().collect();
+    /// let lists = [&a, &b];
+    /// assert_eq!(SkipLinkedList::iter_chain(&lists).collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn iter_chain<'a>(lists: &'a [&'a SkipLinkedList<T>]) -> impl Iterator<Item = &'a T> where T: 'a {
+        lists.iter().flat_map(|list| list.iter())
+    }
+
+    /// Returns an iterator over every `step`-th element of the list, starting at index 0.
+    ///
+    /// Each element is reached by a fresh `O(log n)` seek down the tower, rather than walking the
+    /// elements in between.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list = skip_linked_list::SkipLinkedList::new();
+    /// for i in 0..10 {
+    ///     list.push_back(i);
+    /// }
+    /// assert_eq!(list.step_by_iter(3).collect::<Vec<_>>(), vec![&0, &3, &6, &9]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step == 0`.
+    pub fn step_by_iter(&self, step: usize) -> impl Iterator<Item = &T> {
+        if step == 0 {
+            panic!("step should be > 0");
+        }
+        (0..self.size).step_by(step).filter_map(move |i| self.get(i))
+    }
+
+    /// Keeps only the elements at indices `0, n, 2n, ...`, dropping the rest in one sweep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: skip_linked_list::SkipLinkedList<_> = (0..10).collect();
+    /// list.decimate(3);
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n == 0`.
+    pub fn decimate(&mut self, n: usize) {
+        if n == 0 {
+            panic!("step should be > 0");
+        }
+        let marked: Vec<bool> = (0..self.size).map(|i| i % n != 0).collect();
+        self.remove_marked(&marked, drop);
+    }
+}
+
+impl<T> Default for SkipLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for SkipLinkedList<T> {
+    /// Clones the elements and rebuilds the same tower over them, so the clone has the same
+    /// structure as the original and shares none of its nodes.
+    fn clone(&self) -> Self {
+        let mut list = self.new_like();
+        list.rng = self.rng.clone();
+        list.seed = self.seed;
+        list.append_content(self.iter().cloned());
+
+        let mut levels = Vec::new();
+        let mut level = Some(self.entry.as_ref());
+        while let Some(sentinel) = level {
+            levels.push(sentinel);
+            level = sentinel.down();
+        }
+        let mut lower: Vec<(usize, WeakLink<T>)> = list.content_nodes()
+            .enumerate()
+            .map(|(i, raw_node)| (i + 1, raw_node))
+            .collect();
+        for sentinel in levels.into_iter().rev().skip(1) {
+            let mut promoted = Vec::new();
+            let mut lower_nodes = lower.into_iter();
+            let mut pos = 0;
+            let mut node = sentinel;
+            while let Some(next_node) = node.right() {
+                pos += node.delta();
+                promoted.extend(lower_nodes.by_ref().find(|(lower_pos, _)| *lower_pos == pos));
+                node = next_node;
+            }
+            lower = list.push_index_level(promoted);
+        }
+        list
+    }
+}
+
+impl<T> IntoIterator for SkipLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the list into an iterator.
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<T> FromIterator<T> for SkipLinkedList<T> {
+    /// Builds the list in `O(n)`, laying out the content level first and then every index level
+    /// on top of it in one pass.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.append_bulk(iter.into_iter());
+        list
+    }
+}
+
+impl<T> Extend<T> for SkipLinkedList<T> {
+    /// Appends the elements to the back of the list.
+    ///
+    /// If the iterator's `size_hint` promises at least as many elements as the list already holds,
+    /// the elements are linked in directly and the tower is rebuilt once at the end, which takes
+    /// `O(n + m)` time. Otherwise each element is pushed individually in `O(log n)`.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        if iter.size_hint().0 >= self.size.max(1) {
+            self.append_bulk(iter);
+        } else {
+            for elem in iter {
+                self.push_back(elem);
+            }
+        }
+    }
+}
+
+impl<T> From<BTreeSet<T>> for SkipLinkedList<T> {
+    /// Converts a set into a list in its (sorted) iteration order.
+    fn from(set: BTreeSet<T>) -> Self {
+        set.into_iter().collect()
+    }
+}
+
+impl<T> From<HashSet<T>> for SkipLinkedList<T> {
+    /// Converts a set into a list in its (arbitrary) iteration order.
+    fn from(set: HashSet<T>) -> Self {
+        set.into_iter().collect()
+    }
+}
+
+pub struct IntoIter<T>(SkipLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.0.is_empty() {
+            Some(self.0.pop_front())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.0.is_empty() {
+            Some(self.0.pop_back())
+        } else {
+            None
+        }
+    }
+}
+
+pub struct IntoIterRev<T>(SkipLinkedList<T>);
+
+impl<T> Iterator for IntoIterRev<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.0.is_empty() {
+            Some(self.0.pop_back())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIterRev<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.0.is_empty() {
+            Some(self.0.pop_front())
+        } else {
+            None
+        }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    head: Option<WeakLink<T>>,
+    tail: Option<WeakLink<T>>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.and_then(|raw_node| {
+            if let Node::Content { elem, right, .. } = unsafe { &mut *raw_node.as_ptr() } {
+                self.head = right.as_mut().map(|node| NonNull::from(node.as_mut()));
+                self.len -= 1;
+                Some(elem)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.and_then(|raw_node| {
+            if let Node::Content { elem, prev, .. } = unsafe { &mut *raw_node.as_ptr() } {
+                self.tail = Some(*prev);
+                self.len -= 1;
+                Some(elem)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+pub struct Iter<'a, T> {
+    head: Option<WeakLink<T>>,
+    tail: Option<WeakLink<T>>,
+    len: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.and_then(|raw_node| {
+            if let Node::Content { elem, right, .. } = unsafe { &*raw_node.as_ptr() } {
+                self.head = right.as_ref().map(|node| NonNull::from(node.as_ref()));
+                self.len -= 1;
+                Some(elem)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.and_then(|raw_node| {
+            if let Node::Content { elem, prev, .. } = unsafe { &*raw_node.as_ptr() } {
+                self.tail = Some(*prev);
+                self.len -= 1;
+                Some(elem)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// A read-only cursor over a list, created by `cursor_read`.
+pub struct ReadCursor<'a, T> {
+    entry: &'a Node<T>,
+    // The last visited node and its position on each level, from the top level down.
+    path: Vec<(usize, &'a Node<T>)>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, T> ReadCursor<'a, T> {
+    /// Returns the index the cursor is at.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the element at the cursor, or `None` if the cursor is past the end.
+    pub fn current(&self) -> Option<&'a T> {
+        if self.index < self.len {
+            self.path.last().and_then(|(_, node)| node.elem())
+        } else {
+            None
+        }
+    }
+
+    /// Moves the cursor to the next index.
+    pub fn advance(&mut self) {
+        self.index += 1;
+        if self.index < self.len {
+            let (pos, node) = self.path.last_mut().unwrap();
+            *pos += 1;
+            *node = node.right().unwrap();
+        }
+    }
+
+    /// Moves the cursor to index `i`.
+    ///
+    /// Seeking backwards starts over from the top of the tower.
+    pub fn seek(&mut self, i: usize) {
+        self.index = i;
+        let target = (i + 1).min(self.len);
+        if target < self.path.last().unwrap().0 {
+            self.path.clear();
+            self.path.push((0, self.entry));
+        }
+        let mut level = self.path.len() - 1;
+        // Nodes on upper levels never sit past the ones below them, so climb until a node's span
+        // covers the target.
+        while level > 0 {
+            let (pos, node) = self.path[level];
+            if node.right().is_none() || pos + node.delta() > target {
+                break;
+            }
+            level -= 1;
+        }
+        self.path.truncate(level + 1);
+        let (mut pos, mut node) = self.path[level];
+        loop {
+            while pos + node.delta() <= target {
+                pos += node.delta();
+                node = node.right().unwrap();
+            }
+            *self.path.last_mut().unwrap() = (pos, node);
+            node = match node.down() {
+                Some(next_node) => next_node,
+                None => return,
+            };
+            self.path.push((pos, node));
+        }
+    }
+}
+
+/// An immutable copy of a list's elements, created by `snapshot`.
+pub struct Snapshot<T> {
+    elems: Arc<[T]>,
+}
+
+impl<T> Snapshot<T> {
+    /// Returns the number of elements in the snapshot.
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    /// Returns `true` if the snapshot has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elems.is_empty()
+    }
+
+    /// Returns the element at index `i`, or `None` if it's out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.elems.get(i)
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.elems.iter()
+    }
+}
+
+impl<T> Clone for Snapshot<T> {
+    fn clone(&self) -> Self {
+        Snapshot { elems: Arc::clone(&self.elems) }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Snapshot<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems.iter()
+    }
+}
+
+/// A parallel iterator over the elements of a list, created by `par_iter` with the `rayon`
+/// feature.
+///
+/// The list is cut into one chunk per thread with `index_bounds`, and each chunk is reached by an
+/// `O(log n)` seek before it is walked along the content level.
+#[cfg(feature = "rayon")]
+pub struct ParIter<'a, T> {
+    chunks: Vec<ParChunk<'a, T>>,
+}
+
+#[cfg(feature = "rayon")]
+struct ParChunk<'a, T>(Iter<'a, T>);
+
+// The chunks only ever read the nodes, through a shared borrow of the list, so they may be walked
+// on other threads whenever the elements themselves may be shared.
+#[cfg(feature = "rayon")]
+unsafe impl<'a, T: Sync> Send for ParChunk<'a, T> {}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> Iterator for ParChunk<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync> rayon::iter::ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        use rayon::iter::IntoParallelIterator;
+        self.chunks.into_par_iter().flat_map_iter(|chunk| chunk).drive_unindexed(consumer)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync> rayon::iter::IntoParallelIterator for &'a SkipLinkedList<T> {
+    type Iter = ParIter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let chunks = self.index_bounds(rayon::current_num_threads())
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .map(|range| ParChunk(self.range_iter(range)))
+            .collect();
+        ParIter { chunks }
+    }
+}
+
+/// The 64-bit FNV-1a hash, used by `checksum`. Integers are written in little-endian order and
+/// `usize` as a `u64`, so the hash doesn't depend on the platform.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+const WIDTH: usize = 4;
+
+impl<T> SkipLinkedList<T> where T: Display {
+
+    /// Prints the internals of the list.
+    pub fn visualize(&self) {
+        print!("{}", self.render());
+    }
+
+    /// Renders the internals of the list, one line per level, as printed by `visualize`.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let mut option_node = Some(&self.entry);
+        while let Some(node) = option_node.take() {
+            Self::render_level(&mut out, Some(node));
+            match node.as_ref() {
+                Node::Sentinel { down, .. } => option_node = down.as_ref(),
+                _ => break,
+            }
+        }
+        out
+    }
+
+    /// Writes the elements to `w`, separated by spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// let mut out = Vec::new();
+    /// list.write_to(&mut out).unwrap();
+    /// assert_eq!(out, b"1 2 3");
+    /// ```
+    pub fn write_to<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        self.write_separated(w, " ")
+    }
+
+    /// Writes the elements to `w`, one per line.
+    pub fn write_lines_to<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        self.write_separated(w, "\n")?;
+        if self.size > 0 {
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    fn write_separated<W: io::Write>(&self, w: &mut W, separator: &str) -> io::Result<()> {
+        for (i, elem) in self.iter().enumerate() {
+            if i > 0 {
+                w.write_all(separator.as_bytes())?;
+            }
+            write!(w, "{}", elem)?;
+        }
+        Ok(())
+    }
+
+    fn render_level(out: &mut String, option_node: Option<&Link<T>>) {
+        let mut option_node = option_node;
+        let mut last_delta = 0;
+        while let Some(node) = option_node.take() {
+            match node.as_ref() {
+                Node::Sentinel { right, delta, .. } => {
+                    write!(out, "{delta:>width$}", delta=format!("+{}", delta), width=WIDTH).unwrap();
+                    last_delta = *delta;
+                    option_node = right.as_ref();
+                },
+                Node::Index { right, delta, .. } => {
+                    write!(out, "{delta:>width$}", delta=format!("+{}", delta), width=(last_delta*WIDTH)).unwrap();
+                    last_delta = *delta;
+                    option_node = right.as_ref();
+                },
+                Node::Content { right, elem, .. } => {
+                    write!(out, "{elem:>width$}", elem=elem, width=WIDTH).unwrap();
+                    option_node = right.as_ref();
+                },
+            }
+        }
+        out.push('\n');
+    }
+}
+
+impl<A, B> SkipLinkedList<(A, B)> {
+    /// Splits a list of pairs into a list of the first elements and a list of the second ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SkipLinkedList<_> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+    /// let (left, right) = list.unzip();
+    /// assert_eq!(left.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(right.into_iter().collect::<Vec<_>>(), vec!['a', 'b']);
+    /// ```
+    pub fn unzip(self) -> (SkipLinkedList<A>, SkipLinkedList<B>) {
+        let mut left = self.new_mapped();
+        let mut right = self.new_mapped();
+        let mut seconds = Vec::with_capacity(self.size);
+        left.append_bulk(self.into_iter().map(|(a, b)| {
+            seconds.push(b);
+            a
+        }));
+        right.append_bulk(seconds.into_iter());
+        (left, right)
+    }
+}
+
+impl<U> SkipLinkedList<SkipLinkedList<U>> {
+    /// Concatenates the inner lists, in order, into one list.
+    ///
+    /// Each inner list is joined with `append`, so this takes `O(log n)` per inner list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_linked_list::SkipLinkedList;
+    ///
+    /// let lists: SkipLinkedList<SkipLinkedList<_>> = vec![vec![1, 2], vec![], vec![3]]
+    ///     .into_iter()
+    ///     .map(|vec| vec.into_iter().collect())
+    ///     .collect();
+    /// assert_eq!(lists.flatten().into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn flatten(self) -> SkipLinkedList<U> {
+        let mut list = self.new_mapped();
+        for mut inner in self {
+            list.append(&mut inner);
+        }
+        list
+    }
+}
+
+impl SkipLinkedList<i32> {
+    /// Builds a list from whitespace-separated integers read from `reader`.
+    ///
+    /// Fails with `InvalidData` on the first token that is not an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = skip_linked_list::SkipLinkedList::from_reader("1 2\n3".as_bytes()).unwrap();
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<SkipLinkedList<i32>> {
+        let mut elems = Vec::new();
+        for line in BufReader::new(reader).lines() {
+            for token in line?.split_whitespace() {
+                let elem = token.parse::<i32>()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {:?}", e, token)))?;
+                elems.push(elem);
+            }
+        }
+        Ok(elems.into_iter().collect())
+    }
+}
+
+impl<T> Node<T> {
+    fn right_mut(&mut self) -> &mut Option<Link<T>> {
+        match self {
+            Node::Sentinel { right, .. } => right,
+            Node::Content { right, .. }  => right,
+            Node::Index { right, .. } => right,
+        }
+    }
+
+    fn right(&self) -> Option<&Link<T>> {
+        match self {
+            Node::Sentinel { right, .. } => right.as_ref(),
+            Node::Content { right, .. }  => right.as_ref(),
+            Node::Index { right, .. } => right.as_ref(),
+        }
+    }
+
+    fn down(&self) -> Option<&Node<T>> {
+        match self {
+            Node::Sentinel { down, .. } => down.as_deref(),
+            Node::Index { down: raw_node, .. } => Some(unsafe { raw_node.as_ref() }),
+            Node::Content { .. } => None,
+        }
+    }
+
+    /// Returns the index node directly above this one, if any. Sentinels have none.
+    fn up(&self) -> Option<&Node<T>> {
+        match self {
+            Node::Sentinel { .. } => None,
+            Node::Index { up, .. } | Node::Content { up, .. } => up.as_ref().map(|raw_node| unsafe { raw_node.as_ref() }),
+        }
+    }
+
+    /// Returns the node to the left on the same level. Sentinels have none.
+    #[cfg(test)]
+    fn prev(&self) -> Option<WeakLink<T>> {
+        match self {
+            Node::Sentinel { .. } => None,
+            Node::Index { prev, .. } | Node::Content { prev, .. } => Some(*prev),
+        }
+    }
+
+    fn set_up(&mut self, raw_up: Option<WeakLink<T>>) {
+        if let Node::Index { up, .. } | Node::Content { up, .. } = self {
+            *up = raw_up;
+        }
+    }
+
+    /// Like the other descents below, adds the number of links followed, right and down, to
+    /// `visits`.
+    fn insert(start_node: &mut Node<T>, start_i: usize, elem: T, rng: &mut StdRng, p: f64, visits: &mut usize) -> Option<WeakLink<T>> {
+        let mut node = start_node;
+        let mut i = start_i;
+
+        while node.delta() < i {
+            i -= node.delta();
+            node = node.right_mut().as_mut().unwrap();
+            *visits += 1;
+        }
+        node.insert_at(i, elem, rng, p, visits)
+    }
+
+    fn get<'a>(start_node: &'a Node<T>, start_i: usize, visits: &mut usize) -> Option<&'a T> {
+        let mut node = start_node;
+        let mut i = start_i;
+
+        while node.delta() <= i {
+            i -= node.delta();
+            node = node.right().unwrap();
+            *visits += 1;
+        }
+        node.get_at(i, visits)
+    }
+
+    fn seek<'a>(start_node: &'a Node<T>, start_i: usize, visits: &mut usize) -> &'a Node<T> {
+        let mut node = start_node;
+        let mut i = start_i;
+
+        loop {
+            while node.delta() <= i {
+                i -= node.delta();
+                node = node.right().unwrap();
+                *visits += 1;
+            }
+            node = match node {
+                Node::Sentinel { down: Some(next_node), .. } => next_node,
+                Node::Index { down: raw_node, .. } => unsafe { raw_node.as_ref() },
+                _ => return node,
+            };
+            *visits += 1;
+        }
+    }
+
+    fn seek_mut(start_node: &mut Node<T>, start_i: usize) -> &mut Node<T> {
+        let mut node = start_node;
+        let mut i = start_i;
+
+        loop {
+            while node.delta() <= i {
+                i -= node.delta();
+                node = node.right_mut().as_mut().unwrap();
+            }
+            node = match node {
+                Node::Sentinel { down: Some(next_node), .. } => next_node,
+                Node::Index { down: raw_node, .. } => unsafe { raw_node.as_mut() },
+                _ => return node,
+            };
+        }
+    }
+
+    /// Returns the element at the position of this node, following down links to the content level.
+    fn down_elem(&self) -> Option<&T> {
+        let mut node = self;
+        loop {
+            node = match node {
+                Node::Index { down: raw_node, .. } => unsafe { raw_node.as_ref() },
+                Node::Content { elem, .. } => return Some(elem),
+                Node::Sentinel { .. } => return None,
+            };
+        }
+    }
+
+    fn elem(&self) -> Option<&T> {
+        match self {
+            Node::Content { elem, .. } => Some(elem),
+            _ => None,
+        }
+    }
+
+    fn get_at(&self, i: usize, visits: &mut usize) -> Option<&T> {
+        if let Node::Sentinel { down: Some(_), .. } | Node::Index { .. } = self {
+            *visits += 1;
+        }
+        match self {
+            Node::Sentinel { down: Some(node), .. } => Node::get(node, i, visits),
+            Node::Index { down: raw_node, .. } => Node::get(unsafe { raw_node.as_ref() }, i, visits),
+            Node::Content { elem, .. } if i == 0 => Some(elem),
+            _ => None,
+        }
+    }
+
+    fn get_mut(start_node: &mut Node<T>, start_i: usize) -> Option<&mut T> {
         let mut node = start_node;
         let mut i = start_i;
 
-        while node.delta() < i {
-            i -= node.delta();
-            node = node.right_mut().as_mut().unwrap();
+        while node.delta() <= i {
+            i -= node.delta();
+            node = node.right_mut().as_mut().unwrap();
+        }
+        node.get_at_mut(i)
+    }
+
+    fn get_at_mut(&mut self, i: usize) -> Option<&mut T> {
+        match self {
+            Node::Sentinel { down: Some(node), .. } => Node::get_mut(node, i),
+            Node::Index { down: raw_node, .. } => Node::get_mut(unsafe { raw_node.as_mut() }, i),
+            Node::Content { elem, .. } if i == 0 => Some(elem),
+            _ => None,
+        }
+    }
+
+    /// Points the back link of the node to the right at this node.
+    fn relink_right(&mut self) {
+        let raw_self = NonNull::from(&mut *self);
+        if let Some(Node::Index { prev, .. }) | Some(Node::Content { prev, .. }) = self.right_mut().as_deref_mut() {
+            *prev = raw_self;
+        }
+    }
+
+    fn insert_content_after(&mut self, elem: T) -> Option<WeakLink<T>> {
+        let raw_self = NonNull::from(&mut *self);
+        let right = self.right_mut();
+        let mut new_node = Box::new(Node::Content { elem, prev: raw_self, up: None, handle: None, right: right.take() });
+        new_node.relink_right();
+        let raw_new_node: *mut _ = &mut *new_node;
+        *right = Some(new_node);
+        NonNull::new(raw_new_node)
+    }
+
+    fn insert_index_after(&mut self, i: usize, mut next_level_inserted: WeakLink<T>) -> Option<WeakLink<T>> {
+        let delta = self.delta();
+        let right = self.right_mut();
+        let mut new_node = Box::new(Node::Index {
+            right: right.take(),
+            prev: NonNull::dangling(),
+            down: next_level_inserted,
+            up: None,
+            delta: delta - i,
+        });
+        new_node.relink_right();
+        let raw_new_node: *mut _ = &mut *new_node;
+        unsafe { next_level_inserted.as_mut() }.set_up(NonNull::new(raw_new_node));
+        *right = Some(new_node);
+        self.relink_right();
+        *self.delta_mut().unwrap() = i;
+        NonNull::new(raw_new_node)
+    }
+
+    fn insert_at(&mut self, i: usize, elem: T, rng: &mut StdRng, p: f64, visits: &mut usize) -> Option<WeakLink<T>> {
+        match self {
+            Node::Content { .. } | Node:: Sentinel { down: None, .. } => self.insert_content_after(elem),
+            Node::Sentinel { down: Some(node), delta, .. } => {
+                *delta += 1;
+                *visits += 1;
+                match (Node::insert(node, i, elem, rng, p, visits), rng.gen_bool(p)) {
+                    (Some(next_level_inserted), true) => self.insert_index_after(i, next_level_inserted),
+                    _ => None,
+                }
+            },
+            Node::Index { down: raw_node, delta, .. } => {
+                *delta += 1;
+                *visits += 1;
+                match (Node::insert(unsafe { raw_node.as_mut() }, i, elem, rng, p, visits), rng.gen_bool(p)) {
+                    (Some(next_level_inserted), true) => self.insert_index_after(i, next_level_inserted),
+                    _ => None,
+                }
+            },
+        }
+    }
+
+    fn remove(start_node: &mut Node<T>, i: usize, visits: &mut usize) -> T {
+        let mut i = i;
+        let mut node = start_node;
+
+        while node.delta() <= i {
+            i -= node.delta();
+            node = node.right_mut().as_mut().unwrap();
+            *visits += 1;
+        }
+        node.remove_after(i, visits)
+    }
+
+    fn remove_after(&mut self, i: usize, visits: &mut usize) -> T {
+        match self {
+            Node::Sentinel { down: Some(node), delta, .. } => {
+                *visits += 1;
+                let removed = Node::remove(node, i, visits);
+                if *delta == i + 1 {
+                    self.remove_right();
+                } else {
+                    *delta -= 1;
+                };
+                removed
+            },
+            Node::Index { down: raw_node, delta, .. } => {
+                *visits += 1;
+                let removed = Node::remove(unsafe { raw_node.as_mut() }, i, visits);
+                if *delta == i + 1 {
+                    self.remove_right();
+                } else {
+                    *delta -= 1;
+                }
+                removed
+            },
+            Node::Sentinel { down: None, .. } => self.remove_right().unwrap(),
+            Node::Content {.. } => self.remove_right().unwrap(),
+        }
+    }
+
+    fn remove_right(&mut self) -> Option<T> {
+        let right = self.right_mut();
+        let mut removed = right.take().unwrap();
+        *right = removed.right_mut().take();
+        self.relink_right();
+        if let Some(delta) = self.delta_mut() {
+            *delta += removed.delta() - 1;
+        }
+        match *removed {
+            Node::Content { elem, .. } => Some(elem),
+            _ => None,
+        }
+    }
+
+    fn delta(&self) -> usize {
+        match self {
+            Node::Sentinel { delta, .. } => *delta,
+            Node::Content { .. } => 1,
+            Node::Index { delta, .. } => *delta,
+        }
+    }
+
+    fn delta_mut(&mut self) -> Option<&mut usize> {
+        match self {
+            Node::Sentinel { delta, .. } => Some(delta),
+            Node::Content { .. } => None,
+            Node::Index { delta, .. } => Some(delta),
+        }
+    }
+
+    fn drop_after(sentinel: &mut Node<T>) {
+        if let Some(mut node) = sentinel.right_mut().take() {
+            while let Some(next_node) = node.right_mut().take() {
+                node = next_node;
+            }
+        }
+        if let Node::Sentinel { down: Some(next_sentinel), .. } = sentinel {
+            Node::drop_after(next_sentinel);
+        }
+    }
+}
+
+#[cfg(test)]
+impl<T> SkipLinkedList<T> {
+
+    /// Returns `true` if any node, on any level, belongs to both lists.
+    pub(crate) fn shares_nodes_with(&self, other: &SkipLinkedList<T>) -> bool {
+        let nodes = self.all_nodes();
+        other.all_nodes().iter().any(|node| nodes.contains(node))
+    }
+
+    fn all_nodes(&self) -> HashSet<*const Node<T>> {
+        let mut nodes = HashSet::new();
+        let mut level = Some(self.entry.as_ref());
+        while let Some(sentinel) = level {
+            let mut node = Some(sentinel);
+            while let Some(current) = node {
+                nodes.insert(current as *const Node<T>);
+                node = current.right().map(|next_node| next_node.as_ref());
+            }
+            level = sentinel.down();
+        }
+        nodes
+    }
+
+    /// Returns `true` if some level does not end within `size + height` steps, which can only
+    /// happen if its right links loop back on themselves.
+    pub(crate) fn has_cycle(&self) -> bool {
+        let bound = self.size + self.height();
+        let mut level = Some(self.entry.as_ref());
+        while let Some(sentinel) = level {
+            let mut node = sentinel;
+            let mut steps = 0;
+            while let Some(next_node) = node.right() {
+                steps += 1;
+                if steps > bound {
+                    return true;
+                }
+                node = next_node;
+            }
+            level = sentinel.down();
+        }
+        false
+    }
+
+    /// Returns the address of the element at each index.
+    pub(crate) fn to_index_map(&self) -> Vec<*const T> {
+        self.iter().map(|elem| elem as *const T).collect()
+    }
+
+    /// Checks that `iter` and `get` yield the very same element at every index. The two descend the
+    /// tower independently, so this catches either one drifting from the other.
+    pub(crate) fn iter_matches_get(&self) -> bool {
+        let mut count = 0;
+        for (i, elem) in self.iter().enumerate() {
+            match self.get(i) {
+                Some(got) if std::ptr::eq(got, elem) => count += 1,
+                _ => return false,
+            }
+        }
+        count == self.size && self.get(self.size).is_none()
+    }
+
+    /// Checks that the down link of every index node points at the node with the same position
+    /// on the level directly below it, that the up and back links point the other way, and that
+    /// every level spans `len + 1` positions.
+    pub(crate) fn validate_down_pointers(&self) -> bool {
+        let mut upper: Option<HashMap<usize, *const Node<T>>> = None;
+        let mut upper_nodes: HashMap<usize, *const Node<T>> = HashMap::new();
+        let mut sentinel = Some(self.entry.as_ref());
+        while let Some(level) = sentinel {
+            let mut downs = HashMap::new();
+            let mut nodes = HashMap::new();
+            let mut node = Some(level);
+            let mut pos = 0;
+            let mut previous = None;
+            while let Some(current) = node {
+                nodes.insert(pos, current as *const Node<T>);
+                let expected_up = if pos > 0 { upper_nodes.get(&pos).copied() } else { None };
+                if current.up().map(|upper_node| upper_node as *const Node<T>) != expected_up {
+                    return false;
+                }
+                if current.prev().map(|raw_node| raw_node.as_ptr() as *const Node<T>) != previous {
+                    return false;
+                }
+                previous = Some(current as *const Node<T>);
+                match current {
+                    Node::Sentinel { down: Some(next_node), .. } => downs.insert(pos, next_node.as_ref() as *const _),
+                    Node::Index { down: raw_node, .. } => downs.insert(pos, raw_node.as_ptr() as *const _),
+                    _ => None,
+                };
+                pos += current.delta();
+                node = current.right().map(|next_node| next_node.as_ref());
+            }
+            if pos != self.size + 1 {
+                return false;
+            }
+            if let Some(upper) = upper {
+                if upper.iter().any(|(pos, down)| nodes.get(pos) != Some(down)) {
+                    return false;
+                }
+            }
+            upper = Some(downs);
+            upper_nodes = nodes;
+            sentinel = match level {
+                Node::Sentinel { down: Some(next_node), .. } => Some(next_node.as_ref()),
+                _ => None,
+            };
+        }
+        true
+    }
+}
+
+impl<T> Drop for SkipLinkedList<T> {
+    fn drop(&mut self) {
+        Node::drop_after(&mut self.entry);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::seq::SliceRandom;
+    use rand::thread_rng;
+
+    fn setup_list() -> SkipLinkedList<i32> {
+        let mut list = SkipLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(30);
+        list.push_front(20);
+        list.push_front(10);
+        list.insert(3, 100);
+        list
+    }
+
+    #[test]
+    #[allow(clippy::useless_vec)]
+    fn basics() {
+        let mut list = setup_list();
+        assert_eq!(list.len(), 7);
+        let expected = vec![10, 20, 30, 100, 1, 2, 3];
+        for (i, elem) in expected.iter().enumerate() {
+            assert_eq!(list.get(i), Some(elem));
+        }
+        assert_eq!(list.get(10), None);
+        assert_eq!(list.remove(0), 10);
+        assert_eq!(list.remove(0), 20);
+        assert_eq!(list.remove(4), 3);
+        assert_eq!(list.remove(2), 1);
+    }
+
+    #[test]
+    fn small_random() {
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+
+        let mut size = 0;
+        for _ in 0..1000 {
+            size += 1;
+            let elem: i32 = thread_rng().gen();
+            let idx: usize = thread_rng().gen_range(0, size);
+            list.insert(idx, elem);
+            vec.insert(idx, elem);
+        }
+        assert_eq!(list.len(), vec.len());
+        for i in 0..1000 {
+            assert_eq!(list.get(i), vec.get(i));
+        }
+        assert!(list.iter_matches_get());
+
+        for &probability in [0.0, 0.1, 0.5, 0.9].iter() {
+            list.retune(probability);
+            assert!(list.iter_matches_get());
+            for _ in 0..100 {
+                let idx = thread_rng().gen_range(0, list.len());
+                list.remove(idx);
+                list.insert(thread_rng().gen_range(0, list.len() + 1), 0);
+            }
+            assert!(list.iter_matches_get());
+        }
+    }
+
+    #[test]
+    fn swap_adjacent() {
+        let mut list: SkipLinkedList<i32> = (0..5).collect();
+        list.swap_adjacent(0);
+        list.swap_adjacent(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 0, 2, 4, 3]);
+        assert_eq!(list.back(), Some(&3));
+
+        let mut vec: Vec<i32> = (0..300).map(|_| thread_rng().gen()).collect();
+        let mut list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        for _ in 0..1000 {
+            let i = thread_rng().gen_range(0, vec.len() - 1);
+            vec.swap(i, i + 1);
+            list.swap_adjacent(i);
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec);
+        assert!(list.iter_matches_get());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_swap_adjacent() {
+        let mut list: SkipLinkedList<i32> = (0..5).collect();
+        list.swap_adjacent(4);
+    }
+
+    #[test]
+    fn is_empty() {
+        let mut list = SkipLinkedList::new();
+        assert!(list.is_empty());
+        list.push_front(1);
+        assert!(!list.is_empty());
+        assert_eq!(list.pop_front(), 1);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::useless_vec)]
+    fn iter() {
+        let list = setup_list();
+        let mut iter = list.iter();
+        let expected = vec![10, 20, 30, 100, 1, 2, 3];
+        for elem in expected.iter() {
+            assert_eq!(iter.next(), Some(elem));
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::useless_vec, clippy::while_let_on_iterator)]
+    fn iter_mut() {
+        let mut list = setup_list();
+        let mut iter_mut = list.iter_mut();
+        while let Some(elem) = iter_mut.next() {
+            *elem += 1;
+        }
+        let expected = vec![11, 21, 31, 101, 2, 3, 4];
+        let mut iter = list.iter();
+        for elem in expected.iter() {
+            assert_eq!(iter.next(), Some(elem));
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut_rev() {
+        let mut list = setup_list();
+        let mut order = 0;
+        for elem in list.iter_mut_rev() {
+            *elem = *elem * 10 + order;
+            order += 1;
+        }
+        assert_eq!(order, 7);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![106, 205, 304, 1003, 12, 21, 30]);
+
+        let mut list = SkipLinkedList::<i32>::new();
+        assert_eq!(list.iter_mut_rev().count(), 0);
+    }
+
+    #[test]
+    fn content_nodes() {
+        let list = setup_list();
+        assert_eq!(list.content_nodes().count(), list.len());
+        let elems: Vec<i32> = list.content_nodes()
+            .map(|raw_node| *unsafe { raw_node.as_ref() }.elem().unwrap())
+            .collect();
+        assert_eq!(elems, vec![10, 20, 30, 100, 1, 2, 3]);
+        assert_eq!(SkipLinkedList::<i32>::new().content_nodes().count(), 0);
+    }
+
+    #[test]
+    fn for_each_mut_range() {
+        let mut list = setup_list();
+        list.for_each_mut_range(2..5, |elem| *elem *= 2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 60, 200, 2, 2, 3]);
+        list.for_each_mut_range(5.., |elem| *elem += 1);
+        list.for_each_mut_range(..=0, |elem| *elem = 0);
+        list.for_each_mut_range(3..3, |_| panic!());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 20, 60, 200, 2, 3, 4]);
+        list.for_each_mut_range(.., |elem| *elem = -*elem);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, -20, -60, -200, -2, -3, -4]);
+
+        let mut list: SkipLinkedList<usize> = (0..1000).collect();
+        for _ in 0..20 {
+            let a = thread_rng().gen_range(0, 1001);
+            let b = thread_rng().gen_range(a, 1001);
+            let mut visited = Vec::new();
+            list.for_each_mut_range(a..b, |elem| visited.push(*elem));
+            assert_eq!(visited, (a..b).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_for_each_mut_range() {
+        let mut list = setup_list();
+        list.for_each_mut_range(5..8, |_| ());
+    }
+
+    #[test]
+    fn range_iter() {
+        let list = setup_list();
+        assert_eq!(list.range_iter(3..3).count(), 0);
+        assert_eq!(list.range_iter(1..4).rev().copied().collect::<Vec<_>>(), vec![100, 30, 20]);
+
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for _ in 0..2000 {
+            if vec.is_empty() || thread_rng().gen_bool(0.7) {
+                let i = thread_rng().gen_range(0, vec.len() + 1);
+                let elem: i64 = thread_rng().gen_range(-100, 100);
+                vec.insert(i, elem);
+                list.insert(i, elem);
+            } else {
+                let i = thread_rng().gen_range(0, vec.len());
+                assert_eq!(list.remove(i), vec.remove(i));
+            }
+            let a = thread_rng().gen_range(0, vec.len() + 1);
+            let b = thread_rng().gen_range(a, vec.len() + 1);
+            assert!(list.range_iter(a..b).eq(vec[a..b].iter()));
+        }
+    }
+
+    #[test]
+    fn double_ended() {
+        let mut list = setup_list();
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 7);
+        assert_eq!(iter.next(), Some(&10));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next(), Some(&20));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(&1));
+        assert_eq!(iter.next(), Some(&30));
+        assert_eq!(iter.next_back(), Some(&100));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1, 100, 30, 20, 10]);
+        for (i, elem) in list.iter_mut().rev().enumerate() {
+            *elem = i as i32;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn back() {
+        let mut list = SkipLinkedList::new();
+        assert_eq!(list.back(), None);
+        list.push_back(1);
+        assert_eq!(list.back(), Some(&1));
+        list.push_front(0);
+        list.insert(1, 5);
+        assert_eq!(list.back(), Some(&1));
+        *list.back_mut().unwrap() = 2;
+        assert_eq!(list.pop_back(), 2);
+        assert_eq!(list.back(), Some(&5));
+        list.pop_back();
+        list.pop_back();
+        assert_eq!(list.back(), None);
+        assert_eq!(list.back_mut(), None);
+    }
+
+    #[test]
+    fn no_cycle() {
+        let mut list = SkipLinkedList::new();
+        assert!(!list.has_cycle());
+        for i in 0..500 {
+            list.insert(thread_rng().gen_range(0, i + 1), i);
+        }
+        assert!(!list.has_cycle());
+        list.retune(0.9);
+        assert!(!list.has_cycle());
+        list.decimate(3);
+        assert!(!list.has_cycle());
+    }
+
+    #[test]
+    fn index_map() {
+        let mut list: SkipLinkedList<i32> = (0..100).collect();
+        let before = list.to_index_map();
+        let tracked = before[60];
+        list.remove(10);
+        list.remove(0);
+        list.remove(57);
+        let after = list.to_index_map();
+        assert_eq!(after.len(), 97);
+        assert_eq!(after[57], tracked);
+        assert_eq!(list.get(57), Some(&60));
+        assert_eq!(&after[..9], &before[1..10]);
+        assert_eq!(&after[57..], &before[60..]);
+
+        list.insert(0, -1);
+        assert_eq!(list.to_index_map()[58], tracked);
+    }
+
+    #[test]
+    fn back_links_random() {
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for _ in 0..2000 {
+            if vec.is_empty() || thread_rng().gen_bool(0.6) {
+                let elem: i32 = thread_rng().gen();
+                let idx = thread_rng().gen_range(0, vec.len() + 1);
+                list.insert(idx, elem);
+                vec.insert(idx, elem);
+            } else {
+                let idx = thread_rng().gen_range(0, vec.len());
+                assert_eq!(list.remove(idx), vec.remove(idx));
+            }
+            assert_eq!(list.back(), vec.last());
+        }
+        assert!(!list.has_cycle());
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec.iter().rev().collect::<Vec<_>>());
+
+        let at = vec.len() / 3;
+        let tail = list.split_off(at);
+        let vec_tail = vec.split_off(at);
+        assert_eq!(list.back(), vec.last());
+        assert_eq!(tail.back(), vec_tail.last());
+        assert!(!list.has_cycle() && !tail.has_cycle());
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec.iter().rev().collect::<Vec<_>>());
+        assert_eq!(tail.iter().rev().collect::<Vec<_>>(), vec_tail.iter().rev().collect::<Vec<_>>());
+
+        let list: SkipLinkedList<_> = vec.iter().copied().collect();
+        assert_eq!(list.back(), vec.last());
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec.iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn from_insertions() {
+        let list = SkipLinkedList::from_insertions(vec![(0, 10), (1, 30), (1, 20)]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30]);
+        let list = SkipLinkedList::from_insertions((0..100).map(|i| (0, i)));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), (0..100).rev().collect::<Vec<_>>());
+        assert_eq!(SkipLinkedList::<i32>::from_insertions(vec![]).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_from_insertions() {
+        SkipLinkedList::from_insertions(vec![(0, 10), (2, 30)]);
+    }
+
+    #[test]
+    fn replay() {
+        let mut ops = Vec::new();
+        let mut vec = Vec::new();
+        for _ in 0..2000 {
+            match thread_rng().gen_range(0, 3) {
+                0 => {
+                    let elem: i32 = thread_rng().gen_range(0, 100);
+                    let idx = thread_rng().gen_range(0, vec.len() + 1);
+                    vec.insert(idx, elem);
+                    ops.push(Op::Insert(idx, elem));
+                },
+                1 if !vec.is_empty() => {
+                    let idx = thread_rng().gen_range(0, vec.len());
+                    vec.remove(idx);
+                    ops.push(Op::Remove(idx));
+                },
+                _ => ops.push(Op::Get(thread_rng().gen_range(0, vec.len() + 1))),
+            }
+        }
+        let list = SkipLinkedList::replay(7, &ops);
+        assert_eq!(list.render(), SkipLinkedList::replay(7, &ops).render());
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec.iter().collect::<Vec<_>>());
+
+        let mut list = SkipLinkedList::with_seed(7);
+        let mut other = SkipLinkedList::with_seed(7);
+        for i in 0..100 {
+            list.push_back(i);
+            other.push_back(i);
+        }
+        assert_eq!(list.render(), other.render());
+    }
+
+    #[test]
+    fn apply() {
+        let ops: Vec<Op<i32>> = crate::ops::generate(&mut thread_rng(), 2000, [5, 3, 2]);
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for op in ops {
+            match op.clone() {
+                Op::Insert(i, elem) => {
+                    vec.insert(i, elem);
+                    assert_eq!(list.apply(op), OpResult::Inserted);
+                },
+                Op::Get(i) => assert_eq!(list.apply(op), OpResult::Got(vec.get(i))),
+                Op::Remove(i) => assert_eq!(list.apply(op), OpResult::Removed(vec.remove(i))),
+            }
+        }
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec.iter().collect::<Vec<_>>());
+        assert_eq!(list.apply(Op::Get(vec.len())), OpResult::Got(None));
+    }
+
+    #[test]
+    fn retune() {
+        let mut list = SkipLinkedList::with_seed(1);
+        for i in 0..10000 {
+            list.push_back(i);
+        }
+        let height = list.height();
+        assert!(height > 8);
+
+        list.retune(0.25);
+        assert!(list.height() < height);
+        assert!(list.height() > 1);
+        for i in 0..10000 {
+            assert_eq!(list.get(i), Some(&i));
+        }
+        assert_eq!(list.back(), Some(&9999));
+        list.insert(5000, 0);
+        assert_eq!(list.get(5000), Some(&0));
+        assert_eq!(list.remove(5001), 5000);
+
+        list.retune(0.0);
+        assert_eq!(list.height(), 1);
+        assert_eq!(list.get(9999), Some(&9999));
+    }
+
+    #[test]
+    fn clone_config() {
+        let mut list: SkipLinkedList<i32> = SkipLinkedList::with_seed(17);
+        list.retune(0.25);
+        list.set_soft_cap(Some(500));
+        let mut other = list.clone_config();
+        for i in 0..600 {
+            let idx = thread_rng().gen_range(0, i as usize / 2 + 1);
+            let _ = list.try_push_back(i);
+            let _ = other.try_push_back(i);
+            list.insert(idx, -i);
+            other.insert(idx, -i);
+            assert_eq!(list.height(), other.height());
+        }
+        assert_eq!(list.render(), other.render());
+
+        let list = SkipLinkedList::new_with_comparator(|a: &i32, b: &i32| b.cmp(a));
+        let mut other = list.clone_config();
+        for i in 0..10 {
+            other.insert_sorted(i);
+        }
+        assert_eq!(other.get(0), Some(&9));
+        assert_eq!(SkipLinkedList::<i32>::bounded(3).clone_config().capacity(), Some(3));
+    }
+
+    #[test]
+    fn rebuild_with_seed() {
+        let mut list: SkipLinkedList<i32> = (0..1000).collect();
+        let mut other = SkipLinkedList::new();
+        for i in (0..1000).rev() {
+            other.push_front(i);
+        }
+        list.rebuild_with_seed(21);
+        other.rebuild_with_seed(21);
+        assert_eq!(list.render(), other.render());
+        assert!(list.validate_down_pointers());
+        list.insert(500, -1);
+        other.insert(500, -1);
+        assert_eq!(list.render(), other.render());
+
+        other.rebuild_with_seed(22);
+        assert_ne!(list.render(), other.render());
+        assert_eq!(list.iter().collect::<Vec<_>>(), other.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_retune() {
+        let mut list: SkipLinkedList<i32> = SkipLinkedList::new();
+        list.retune(1.0);
+    }
+
+    #[test]
+    fn clone_into_vec() {
+        let list = setup_list();
+        let mut out = Vec::with_capacity(16);
+        out.push(5);
+        let ptr = out.as_ptr();
+        list.clone_into_vec(&mut out);
+        assert_eq!(out, vec![10, 20, 30, 100, 1, 2, 3]);
+        assert_eq!(out.as_ptr(), ptr);
+        assert_eq!(out.capacity(), 16);
+        SkipLinkedList::new().clone_into_vec(&mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn is_subsequence_of() {
+        let list: SkipLinkedList<i32> = vec![1, 3].into_iter().collect();
+        let other: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        assert!(list.is_subsequence_of(&other));
+        assert!(other.is_subsequence_of(&other));
+        assert!(!other.is_subsequence_of(&list));
+        let list: SkipLinkedList<i32> = vec![3, 1].into_iter().collect();
+        assert!(!list.is_subsequence_of(&other));
+        let list: SkipLinkedList<i32> = vec![1, 1].into_iter().collect();
+        assert!(!list.is_subsequence_of(&other));
+        assert!(SkipLinkedList::new().is_subsequence_of(&other));
+    }
+
+    #[test]
+    fn into_iter() {
+        let list = setup_list();
+        let expected = vec![10, 20, 30, 100, 1, 2, 3];
+        let mut into_iter = list.into_iter();
+
+        for elem in expected {
+            assert_eq!(into_iter.next(), Some(elem));
+        }
+        assert_eq!(into_iter.next(), None);
+    }
+
+    #[test]
+    fn into_iter_rev() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(list.into_iter_rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        let mut into_iter = setup_list().into_iter_rev();
+        assert_eq!(into_iter.next(), Some(3));
+        assert_eq!(into_iter.next_back(), Some(10));
+        assert_eq!(into_iter.collect::<Vec<_>>(), vec![2, 1, 100, 30, 20]);
+        assert_eq!(SkipLinkedList::<i32>::new().into_iter_rev().next(), None);
+    }
+
+    #[test]
+    fn drop() {
+        let size = 50000;
+        let mut list = SkipLinkedList::new();
+        for _ in 0..size {
+            list.push_front(1);
+        }
+    }
+
+    #[test]
+    fn pops() {
+        let mut list = SkipLinkedList::new();
+        list.push_front(1);
+        list.push_front(2);
+        assert_eq!(list.pop_front(), 2);
+        assert_eq!(list.pop_front(), 1);
+
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(list.pop_back(), 2);
+        assert_eq!(list.pop_back(), 1);
+    }
+
+    #[test]
+    fn pop_back_unlinks_tail_column() {
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for _ in 0..3000 {
+            if vec.is_empty() || thread_rng().gen_bool(0.55) {
+                let elem: i32 = thread_rng().gen();
+                let idx = thread_rng().gen_range(0, vec.len() + 1);
+                list.insert(idx, elem);
+                vec.insert(idx, elem);
+            } else {
+                assert_eq!(list.pop_back(), vec.pop().unwrap());
+                assert_eq!(list.back(), vec.last());
+            }
+        }
+        assert!(list.validate_down_pointers());
+        while let Some(elem) = vec.pop() {
+            assert_eq!(list.pop_back(), elem);
+            assert!(list.validate_down_pointers());
+        }
+        assert!(list.is_empty());
+        list.push_back(1);
+        assert!(list.eq_iter(vec![1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_pop_front() {
+        let mut list: SkipLinkedList<i32> = SkipLinkedList::new();
+        list.pop_front();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_pop_back() {
+        let mut list: SkipLinkedList<i32> = SkipLinkedList::new();
+        list.pop_back();
+    }
+
+    #[test]
+    fn move_to_front_and_back() {
+        let mut list = setup_list();
+        list.move_to_front(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![100, 10, 20, 30, 1, 2, 3]);
+        list.move_to_back(0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30, 1, 2, 3, 100]);
+        list.move_to_back(6);
+        list.move_to_front(0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30, 1, 2, 3, 100]);
+        assert_eq!(list.back(), Some(&100));
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![100, 3, 2, 1, 30, 20, 10]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_move_to_front() {
+        let mut list = setup_list();
+        list.move_to_front(7);
+    }
+
+    #[test]
+    fn bounded() {
+        let mut list = SkipLinkedList::bounded(3);
+        assert_eq!(list.capacity(), Some(3));
+        for i in 0..3 {
+            assert_eq!(list.push_back_bounded(i), None);
+        }
+        for i in 3..10 {
+            assert_eq!(list.push_back_bounded(i), Some(i - 3));
+            assert_eq!(list.len(), 3);
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7, 8, 9]);
+        assert_eq!(list.push_front_bounded(6), Some(9));
+        assert_eq!(list.back(), Some(&8));
+        list.push_back(10);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7, 8, 10]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![10, 8, 7]);
+
+        let mut list = SkipLinkedList::bounded(0);
+        assert_eq!(list.push_front_bounded(1), Some(1));
+        assert_eq!(list.len(), 0);
+
+        let mut list = SkipLinkedList::new();
+        assert_eq!(list.capacity(), None);
+        for i in 0..100 {
+            assert_eq!(list.push_back_bounded(i), None);
+        }
+    }
+
+    #[test]
+    fn get_cow() {
+        let list = setup_list();
+        match list.get_cow(3) {
+            Some(Cow::Borrowed(elem)) => assert!(std::ptr::eq(elem, list.get(3).unwrap())),
+            _ => panic!("expected a borrowed element"),
+        }
+        let mut elem = list.get_cow(0).unwrap();
+        *elem.to_mut() += 1;
+        assert_eq!(*elem, 11);
+        assert_eq!(list.get(0), Some(&10));
+        assert_eq!(list.get_cow(7), None);
+    }
+
+    #[test]
+    fn try_push_back() {
+        let mut list = SkipLinkedList::new();
+        for i in 0..100 {
+            assert_eq!(list.try_push_back(i), Ok(()));
+        }
+        list.set_soft_cap(Some(102));
+        assert_eq!(list.try_push_back(100), Ok(()));
+        assert_eq!(list.try_push_back(101), Ok(()));
+        assert_eq!(list.try_push_back(102), Err(102));
+        assert_eq!(list.len(), 102);
+        assert_eq!(list.back(), Some(&101));
+
+        list.set_soft_cap(Some(50));
+        assert_eq!(list.try_push_back(102), Err(102));
+        assert_eq!(list.len(), 102);
+        list.set_soft_cap(None);
+        assert_eq!(list.try_push_back(102), Ok(()));
+        assert_eq!(list.back(), Some(&102));
+    }
+
+    #[test]
+    fn handles() {
+        let mut list = SkipLinkedList::new();
+        let handles: Vec<Handle<i32>> = (0..10).map(|i| list.push_front_handle(i)).collect();
+        list.insert(5, 100);
+        list.push_back(200);
+        assert_eq!(list.remove_handle(handles[4].clone()), Some(4));
+        assert_eq!(list.remove_handle(handles[4].clone()), None);
+        assert_eq!(list.remove_handle(handles[0].clone()), Some(0));
+        assert_eq!(list.remove_handle(handles[9].clone()), Some(9));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![8, 7, 6, 5, 100, 3, 2, 1, 200]);
+        assert!(list.validate_down_pointers());
+
+        let mut other = SkipLinkedList::new();
+        let handle = other.push_front_handle(1);
+        assert_eq!(list.remove_handle(handle.clone()), None);
+        assert_eq!(other.remove_handle(handle.clone()), Some(1));
+
+        // Nodes freed above are likely reused here, but the stale handles must not match them.
+        let fresh: Vec<_> = (0..10).map(|i| other.push_front_handle(i)).collect();
+        assert_eq!(other.remove_handle(handle), None);
+        assert!(handles.iter().all(|handle| !fresh.contains(handle)));
+        assert_eq!(other.remove_handle(handles[4].clone()), None);
+        assert_eq!(other.len(), 10);
+
+        let mut tail = list.split_off(4);
+        assert_eq!(list.remove_handle(handles[3].clone()), None);
+        assert_eq!(tail.remove_handle(handles[3].clone()), Some(3));
+        list.append(&mut tail);
+        assert_eq!(list.remove_handle(handles[2].clone()), Some(2));
+        list.flatten_levels(1);
+        assert_eq!(list.remove_handle(handles[8].clone()), Some(8));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7, 6, 5, 100, 1, 200]);
+        assert!(list.validate_down_pointers());
+
+        let mut list = SkipLinkedList::new();
+        list.push_front(3);
+        let handle = list.push_front_handle(2);
+        list.push_front(1);
+        list.swap_adjacent(1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3, 2]);
+        list.swap_adjacent(0);
+        assert_eq!(list.remove_handle(handle), Some(2));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 1]);
+
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for i in 0..500 {
+            let handle = list.push_front_handle(i);
+            vec.insert(0, (i, Some(handle)));
+            let j = thread_rng().gen_range(0, list.len() + 1);
+            list.insert(j, -1);
+            vec.insert(j, (-1, None));
+            let j = thread_rng().gen_range(0, list.len() - 1);
+            list.swap_adjacent(j);
+            vec.swap(j, j + 1);
+            if thread_rng().gen_bool(0.5) {
+                let j = thread_rng().gen_range(0, vec.len());
+                match vec.remove(j) {
+                    (elem, Some(handle)) => assert_eq!(list.remove_handle(handle), Some(elem)),
+                    (elem, None) => assert_eq!(list.remove(j), elem),
+                }
+            }
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec.iter().map(|(elem, _)| *elem).collect::<Vec<_>>());
+        assert!(list.validate_down_pointers());
+    }
+
+    #[test]
+    fn get_or() {
+        let list = setup_list();
+        assert_eq!(list.get_or(0, &-1), &10);
+        assert_eq!(list.get_or(6, &-1), &3);
+        assert_eq!(list.get_or(7, &-1), &-1);
+        assert_eq!(list.get_or_else(3, || panic!()), &100);
+        let mut calls = 0;
+        assert_eq!(list.get_or_else(usize::MAX, || {
+            calls += 1;
+            &-1
+        }), &-1);
+        assert_eq!(calls, 1);
+        assert_eq!(SkipLinkedList::new().get_or(0, &5), &5);
+    }
+
+    #[test]
+    fn saturating_and_wrapping() {
+        let list = setup_list();
+        assert_eq!(list.get_saturating(0), Some(&10));
+        assert_eq!(list.get_saturating(6), Some(&3));
+        assert_eq!(list.get_saturating(7), Some(&3));
+        assert_eq!(list.get_saturating(usize::MAX), Some(&3));
+        assert_eq!(list.get_wrapping(3), Some(&100));
+        assert_eq!(list.get_wrapping(7), Some(&10));
+        assert_eq!(list.get_wrapping(17), Some(&100));
+        assert_eq!(list.get_wrapping(usize::MAX), list.get(usize::MAX % 7));
+
+        let empty: SkipLinkedList<i32> = SkipLinkedList::new();
+        assert_eq!(empty.get_saturating(0), None);
+        assert_eq!(empty.get_wrapping(3), None);
+    }
+
+    #[test]
+    fn insert_from_back() {
+        let mut list: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        list.insert_from_back(0, 4);
+        assert_eq!(list.back(), Some(&4));
+        list.insert_from_back(1, 5);
+        list.insert_from_back(list.len(), 0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 5, 4]);
+
+        let mut list = SkipLinkedList::new();
+        list.insert_from_back(0, 'a');
+        assert_eq!(list.get(0), Some(&'a'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_insert_from_back() {
+        let mut list: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        list.insert_from_back(4, 0);
+    }
+
+    #[test]
+    fn insert_interpolated() {
+        let mut list: SkipLinkedList<i32> = vec![10, 30].into_iter().collect();
+        list.insert_interpolated(1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30]);
+        list.insert_interpolated(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 25, 30]);
+
+        let mut list: SkipLinkedList<f64> = vec![1.0, 2.0].into_iter().collect();
+        list.insert_interpolated(1);
+        assert_eq!(list.get(1), Some(&1.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_insert_interpolated() {
+        let mut list: SkipLinkedList<i32> = vec![10, 30].into_iter().collect();
+        list.insert_interpolated(2);
+    }
+
+    #[test]
+    fn insert_evenly() {
+        let mut list: SkipLinkedList<i32> = (0..6).collect();
+        list.insert_evenly(vec![-1, -2, -3]);
+        let positions: Vec<usize> = list.iter().enumerate().filter(|(_, elem)| **elem < 0).map(|(i, _)| i).collect();
+        assert_eq!(positions, vec![2, 4, 6]);
+
+        for (n, m) in [(0, 3), (1, 1), (10, 3), (3, 10), (100, 7), (5, 0)].iter() {
+            let mut list: SkipLinkedList<i32> = (0..*n as i32).collect();
+            list.insert_evenly((0..*m as i32).map(|k| -k - 1));
+            let positions: Vec<usize> = list.iter().enumerate().filter(|(_, elem)| **elem < 0).map(|(i, _)| i).collect();
+            let expected: Vec<usize> = (0..*m).map(|k| (k + 1) * (n + m) / (m + 1)).collect();
+            assert_eq!(positions, expected);
+            let originals: Vec<i32> = list.iter().copied().filter(|elem| *elem >= 0).collect();
+            assert_eq!(originals, (0..*n as i32).collect::<Vec<_>>());
+            let items: Vec<i32> = list.iter().copied().filter(|elem| *elem < 0).collect();
+            assert_eq!(items, (0..*m as i32).map(|k| -k - 1).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn insert_observed() {
+        let mut list = setup_list();
+        let before: Vec<i32> = list.iter().copied().collect();
+        let info = list.insert_observed(2, 7);
+        assert_eq!(info, InsertInfo { index: 2, shifted_from: 2 });
+        assert_eq!(list.get(info.index), Some(&7));
+        for (i, elem) in before.iter().enumerate() {
+            let now = if i >= info.shifted_from { i + 1 } else { i };
+            assert_eq!(list.get(now), Some(elem));
+        }
+
+        let info = list.insert_observed(list.len(), 9);
+        assert_eq!(info.shifted_from, list.len() - 1);
+        assert_eq!(list.back(), Some(&9));
+    }
+
+    #[test]
+    fn compare_and_set() {
+        let mut list = setup_list();
+        assert!(list.compare_and_set(3, &100, 42));
+        assert_eq!(list.get(3), Some(&42));
+
+        assert!(!list.compare_and_set(3, &100, 7));
+        assert_eq!(list.get(3), Some(&42));
+        assert!(!list.compare_and_set(10, &42, 7));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30, 42, 1, 2, 3]);
+    }
+
+    #[test]
+    fn bulk() {
+        let mut list: SkipLinkedList<i32> = (0..100).collect();
+        let mut vec: Vec<i32> = (0..100).collect();
+        for round in 0..4 {
+            list.begin_bulk();
+            assert_eq!(list.height(), 1);
+            for _ in 0..500 {
+                if vec.is_empty() || thread_rng().gen_bool(0.6) {
+                    let i = thread_rng().gen_range(0, vec.len() + 1);
+                    let elem = thread_rng().gen();
+                    list.insert(i, elem);
+                    vec.insert(i, elem);
+                } else {
+                    let i = thread_rng().gen_range(0, vec.len());
+                    assert_eq!(list.remove(i), vec.remove(i));
+                }
+                if round % 2 == 1 && !vec.is_empty() {
+                    let i = thread_rng().gen_range(0, vec.len());
+                    assert_eq!(list.get(i), vec.get(i));
+                }
+            }
+            assert_eq!(list.back(), vec.last());
+            list.end_bulk();
+            assert!(list.validate_down_pointers());
+            assert_eq!(list.iter().collect::<Vec<_>>(), vec.iter().collect::<Vec<_>>());
+            assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec.iter().rev().collect::<Vec<_>>());
+            for (i, elem) in vec.iter().enumerate() {
+                assert_eq!(list.get(i), Some(elem));
+            }
+            list.insert(0, 1);
+            vec.insert(0, 1);
+        }
+
+        let mut list = SkipLinkedList::new();
+        list.begin_bulk();
+        for i in 0..1000 {
+            list.push_back(i);
+        }
+        list.extend(1000..2000);
+        list.push_front(-1);
+        assert_eq!(list.pop_back(), 1999);
+        list.end_bulk();
+        assert!(list.height() > 1);
+        assert!(list.validate_down_pointers());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), (-1..1999).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn finger_search() {
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for _ in 0..1000 {
+            let elem: i32 = thread_rng().gen();
+            let idx: usize = thread_rng().gen_range(0, vec.len() + 1);
+            list.insert(idx, elem);
+            vec.insert(idx, elem);
+        }
+        list.enable_finger_search(true);
+        for i in 0..1000 {
+            assert_eq!(list.get(i), vec.get(i));
+        }
+        for _ in 0..1000 {
+            let idx: usize = thread_rng().gen_range(0, 1010);
+            assert_eq!(list.get(idx), vec.get(idx));
+        }
+        for _ in 0..100 {
+            let idx: usize = thread_rng().gen_range(0, vec.len());
+            assert_eq!(list.remove(idx), vec.remove(idx));
+            assert_eq!(list.get(idx), vec.get(idx));
+            assert_eq!(list.get(idx + 1), vec.get(idx + 1));
+        }
+    }
+
+    #[test]
+    fn split_at_first() {
+        let mut list: SkipLinkedList<_> = vec![1, 2, 3, 4].into_iter().collect();
+        let tail = list.split_at_first(|x| x % 2 == 0).unwrap();
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1]);
+        assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+
+        let mut list: SkipLinkedList<_> = vec![1, 3, 5].into_iter().collect();
+        assert!(list.split_at_first(|x| x % 2 == 0).is_none());
+        assert_eq!(list.len(), 3);
+
+        let tail = list.split_at_first(|_| true).unwrap();
+        assert_eq!(list.len(), 0);
+        assert_eq!(tail.len(), 3);
+    }
+
+    #[test]
+    fn take_front_keeps_config() {
+        let mut list = SkipLinkedList::with_seed(9);
+        let mut other = SkipLinkedList::with_seed(9);
+        list.set_soft_cap(Some(150));
+        other.set_soft_cap(Some(150));
+        list.extend(0..100);
+        other.extend(0..100);
+        let front = list.take_front(40);
+        other.take_front(40);
+        assert!(front.eq_iter(0..40));
+        assert!(list.eq_iter(40..100));
+        assert!(list.validate_down_pointers());
+
+        for i in 0..100 {
+            let idx = thread_rng().gen_range(0, list.len() + 1);
+            let _ = list.try_push_back(i);
+            let _ = other.try_push_back(i);
+            list.insert(idx, -i);
+            other.insert(idx, -i);
+        }
+        assert_eq!(list.len(), 205);
+        assert_eq!(list.render(), other.render());
+    }
+
+    #[test]
+    fn split_off() {
+        for _ in 0..20 {
+            let mut list = SkipLinkedList::new();
+            let mut vec = Vec::new();
+            for i in 0..200 {
+                list.push_back(i);
+                vec.push(i);
+            }
+            let at = thread_rng().gen_range(0, 201);
+            let mut tail = list.split_off(at);
+            let mut vec_tail = vec.split_off(at);
+            assert_eq!(list.len(), vec.len());
+            assert_eq!(tail.len(), vec_tail.len());
+
+            for _ in 0..50 {
+                let elem: i32 = thread_rng().gen();
+                let idx = thread_rng().gen_range(0, vec.len() + 1);
+                list.insert(idx, elem);
+                vec.insert(idx, elem);
+                let idx = thread_rng().gen_range(0, vec_tail.len() + 1);
+                tail.insert(idx, elem);
+                vec_tail.insert(idx, elem);
+            }
+            for (i, elem) in vec.iter().enumerate() {
+                assert_eq!(list.get(i), Some(elem));
+            }
+            for (i, elem) in vec_tail.iter().enumerate() {
+                assert_eq!(tail.get(i), Some(elem));
+            }
+            assert_eq!(list.iter().collect::<Vec<_>>(), vec.iter().collect::<Vec<_>>());
+            assert_eq!(tail.into_iter().collect::<Vec<_>>(), vec_tail);
+        }
+    }
+
+    #[test]
+    fn truncate() {
+        let mut list: SkipLinkedList<i32> = (0..10).collect();
+        list.truncate(10);
+        list.truncate(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(list.back(), Some(&2));
+        list.truncate(0);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn shift() {
+        let mut list: SkipLinkedList<i32> = vec![1, 2, 3, 4].into_iter().collect();
+        list.shift_right(2, 0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 0, 1, 2]);
+        list.shift_left(1, 9);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 9]);
+        list.shift_left(0, 5);
+        list.shift_right(0, 5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 9]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![9, 2, 1, 0]);
+        list.shift_right(10, 7);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7, 7, 7, 7]);
+
+        let mut list: SkipLinkedList<usize> = (0..500).collect();
+        list.shift_right(100, 0);
+        list.shift_left(50, 1);
+        assert!(list.validate_down_pointers());
+        let expected: Vec<usize> = vec![0; 50].into_iter().chain(0..400).chain(vec![1; 50]).collect();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        for (i, elem) in expected.iter().enumerate() {
+            assert_eq!(list.get(i), Some(elem));
+        }
+    }
+
+    #[test]
+    fn replace_range() {
+        let mut list: SkipLinkedList<i32> = (0..7).collect();
+        list.replace_range(2..5, 99);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 99, 5, 6]);
+        list.replace_range(..1, -1);
+        list.replace_range(4.., 100);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![-1, 1, 99, 5, 100]);
+        list.replace_range(2..2, 0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![-1, 1, 0, 99, 5, 100]);
+        list.replace_range(.., 7);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7]);
+        assert_eq!(list.back(), Some(&7));
+
+        let mut vec: Vec<i32> = (0..1000).collect();
+        let mut list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        for i in 0..100 {
+            let a = thread_rng().gen_range(0, vec.len() + 1);
+            let b = thread_rng().gen_range(a, vec.len() + 1);
+            vec.splice(a..b, std::iter::once(-i));
+            list.replace_range(a..b, -i);
+        }
+        assert!(list.eq_iter(vec.iter().copied()));
+        assert!(list.validate_down_pointers());
+        assert!(list.iter_matches_get());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_replace_range() {
+        let mut list: SkipLinkedList<i32> = (0..7).collect();
+        list.replace_range(5..8, 0);
+    }
+
+    #[test]
+    fn truncate_front() {
+        let mut list: SkipLinkedList<i32> = (0..10).collect();
+        list.truncate_front(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7, 8, 9]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![9, 8, 7]);
+        assert!(list.validate_down_pointers());
+        list.truncate_front(3);
+        list.truncate_front(10);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7, 8, 9]);
+        list.push_front(6);
+        assert_eq!(list.get(0), Some(&6));
+        list.truncate_front(0);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn append() {
+        for _ in 0..50 {
+            let n = thread_rng().gen_range(0, 200);
+            let m = thread_rng().gen_range(0, 200);
+            let mut list: SkipLinkedList<usize> = (0..n).collect();
+            let mut other: SkipLinkedList<usize> = (n..n + m).collect();
+            list.append(&mut other);
+            assert_eq!(other.len(), 0);
+            assert_eq!(other.iter().next(), None);
+            assert_eq!(list.len(), n + m);
+            assert!(list.validate_down_pointers());
+            for i in 0..n + m {
+                assert_eq!(list.get(i), Some(&i));
+            }
+            assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), (0..n + m).rev().collect::<Vec<_>>());
+            assert_eq!(list.back(), (n + m).checked_sub(1).as_ref());
+
+            list.insert(n, 1000);
+            list.remove(0);
+            other.push_back(7);
+            assert_eq!(other.iter().copied().collect::<Vec<_>>(), vec![7]);
+        }
+    }
+
+    #[test]
+    fn indices_where() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 3, 4].into_iter().collect();
+        assert_eq!(list.indices_where(|x| x % 2 == 0), vec![1, 3]);
+        assert!(list.indices_where(|x| *x > 4).is_empty());
+        assert_eq!(setup_list().indices_where(|x| *x >= 10), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn rotate() {
+        let mut list = setup_list();
+        list.rotate_left(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![100, 1, 2, 3, 10, 20, 30]);
+        list.rotate_left(0);
+        list.rotate_left(7);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![100, 1, 2, 3, 10, 20, 30]);
+        assert_eq!(list.position(&10), Some(4));
+        assert_eq!(list.position(&11), None);
+
+        let mut list: SkipLinkedList<i32> = vec![3, 1, 2].into_iter().collect();
+        list.rotate_to(&1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        list.rotate_to(&4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+        assert!(list.validate_down_pointers());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_rotate_left() {
+        let mut list = setup_list();
+        list.rotate_left(8);
+    }
+
+    #[test]
+    fn pop_n() {
+        let mut list = SkipLinkedList::new();
+        for i in 0..10 {
+            list.push_back(i);
+        }
+        let front = list.pop_front_n(3);
+        assert_eq!(front.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 6, 7, 8, 9]);
+
+        let back = list.pop_back_n(2);
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![8, 9]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 6, 7]);
+
+        let rest = list.pop_back_n(100);
+        assert_eq!(rest.len(), 5);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop_front_n(1).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_off() {
+        let mut list = setup_list();
+        list.split_off(8);
+    }
+
+    #[test]
+    fn index_bounds() {
+        let list: SkipLinkedList<i32> = (0..10).collect();
+        assert_eq!(list.index_bounds(3), vec![0..4, 4..7, 7..10]);
+        assert_eq!(list.index_bounds(1), vec![0..10]);
+        let list: SkipLinkedList<i32> = (0..2).collect();
+        assert_eq!(list.index_bounds(4), vec![0..1, 1..2, 2..2, 2..2]);
+
+        let list: SkipLinkedList<i32> = (0..100).collect();
+        for chunks in 1..120 {
+            let ranges = list.index_bounds(chunks);
+            assert_eq!(ranges.len(), chunks);
+            assert_eq!(ranges[0].start, 0);
+            assert_eq!(ranges[chunks - 1].end, 100);
+            assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+            let parts = list.clone().split_into(chunks);
+            assert!(ranges.iter().zip(parts.iter()).all(|(range, part)| range.len() == part.len()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_index_bounds() {
+        SkipLinkedList::<i32>::new().index_bounds(0);
+    }
+
+    #[test]
+    fn split_into() {
+        let list: SkipLinkedList<i32> = (0..10).collect();
+        let parts = list.split_into(3);
+        assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), vec![4, 3, 3]);
+        assert_eq!(parts.iter().flat_map(|part| part.iter().copied()).collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+
+        let list: SkipLinkedList<i32> = (0..2).collect();
+        let parts = list.split_into(4);
+        assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), vec![1, 1, 0, 0]);
+
+        for parts in 1..20 {
+            let list: SkipLinkedList<i32> = (0..100).collect();
+            let parts = list.split_into(parts);
+            for part in parts.iter() {
+                assert!(part.validate_down_pointers());
+                assert_eq!(part.iter().rev().count(), part.len());
+            }
+            assert_eq!(parts.into_iter().flatten().collect::<Vec<_>>(), (0..100).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_into() {
+        SkipLinkedList::<i32>::new().split_into(0);
+    }
+
+    #[test]
+    fn clone() {
+        let mut list = SkipLinkedList::with_seed(3);
+        list.extend(0..1000);
+        for i in 0..200 {
+            list.insert(i * 3, i);
+        }
+        let mut other = list.clone();
+        assert!(!list.shares_nodes_with(&other));
+        assert!(list.shares_nodes_with(&list));
+        assert_eq!(other.render(), list.render());
+        assert!(other.validate_down_pointers());
+        assert_eq!(other.iter().rev().collect::<Vec<_>>(), list.iter().rev().collect::<Vec<_>>());
+
+        other.insert(500, 7);
+        list.insert(500, 7);
+        assert_eq!(other.render(), list.render());
+        other.remove(0);
+        *other.back_mut().unwrap() = 0;
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.back(), Some(&999));
+
+        let list = SkipLinkedList::<i32>::new();
+        let other = list.clone();
+        assert!(!list.shares_nodes_with(&other));
+        assert_eq!(other.len(), 0);
+    }
+
+    #[test]
+    fn from_iter() {
+        let vec: Vec<i32> = (0..1000).map(|_| thread_rng().gen()).collect();
+        let mut list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        assert_eq!(list.len(), vec.len());
+        for (i, elem) in vec.iter().enumerate() {
+            assert_eq!(list.get(i), Some(elem));
+        }
+        list.insert(500, 7);
+        assert_eq!(list.get(500), Some(&7));
+        assert_eq!(list.remove(0), vec[0]);
+        assert_eq!(list.get(999), vec.last());
+
+        let empty: SkipLinkedList<i32> = std::iter::empty().collect();
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn extend() {
+        let mut list: SkipLinkedList<i32> = (0..10).collect();
+        list.extend(10..100);
+        list.extend((100..105).filter(|_| true));
+        let mut vec: Vec<i32> = (0..105).collect();
+        assert_eq!(list.len(), 105);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec.iter().collect::<Vec<_>>());
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec.iter().rev().collect::<Vec<_>>());
+
+        for _ in 0..100 {
+            let idx = thread_rng().gen_range(0, vec.len() + 1);
+            list.insert(idx, -1);
+            vec.insert(idx, -1);
+        }
+        for (i, elem) in vec.iter().enumerate() {
+            assert_eq!(list.get(i), Some(elem));
+        }
+
+        let mut list = SkipLinkedList::new();
+        list.extend((0..1000).filter(|_| true));
+        list.extend(std::iter::empty());
+        assert_eq!(list.len(), 1000);
+        assert_eq!(list.back(), Some(&999));
+        assert_eq!(list.get(500), Some(&500));
+    }
+
+    #[test]
+    fn from_sets() {
+        let set: BTreeSet<i32> = (0..100).map(|_| thread_rng().gen_range(0, 1000)).collect();
+        let list = SkipLinkedList::from(set.clone());
+        assert_eq!(list.len(), set.len());
+        assert_eq!(list.iter().collect::<Vec<_>>(), set.iter().collect::<Vec<_>>());
+
+        let set: HashSet<i32> = (0..100).collect();
+        let list = SkipLinkedList::from(set.clone());
+        assert_eq!(list.len(), 100);
+        assert!(list.iter().all(|elem| set.contains(elem)));
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut list = SkipLinkedList::new();
+        let mut vec = Vec::new();
+        for _ in 0..1000 {
+            let elem: i32 = thread_rng().gen_range(0, 100);
+            list.insert_sorted(elem);
+            vec.push(elem);
+        }
+        vec.sort();
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec.iter().collect::<Vec<_>>());
+
+        let mut list = SkipLinkedList::new();
+        for (key, id) in [(2, 0), (1, 1), (2, 2), (1, 3)].iter() {
+            list.insert_sorted((*key, *id));
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![(1, 1), (1, 3), (2, 0), (2, 2)]);
+    }
+
+    #[test]
+    fn deltas() {
+        let list: SkipLinkedList<i32> = vec![1, 3, 6, 10].into_iter().collect();
+        assert_eq!(list.deltas(), vec![2, 3, 4]);
+        let list: SkipLinkedList<i32> = vec![5, 2].into_iter().collect();
+        assert_eq!(list.deltas(), vec![-3]);
+        let list: SkipLinkedList<i32> = vec![5].into_iter().collect();
+        assert_eq!(list.deltas(), vec![]);
+        let list: SkipLinkedList<i32> = SkipLinkedList::new();
+        assert_eq!(list.deltas(), vec![]);
+    }
+
+    #[test]
+    fn transitions() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(list.transitions().collect::<Vec<_>>(), vec![(&1, &2), (&2, &3)]);
+        let list: SkipLinkedList<i32> = vec![1].into_iter().collect();
+        assert_eq!(list.transitions().count(), 0);
+        assert_eq!(SkipLinkedList::<i32>::new().transitions().count(), 0);
+
+        let list: SkipLinkedList<char> = "abab".chars().collect();
+        let mut counts = HashMap::new();
+        for pair in list.transitions() {
+            *counts.entry(pair).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&(&'a', &'b')], 2);
+        assert_eq!(counts[&(&'b', &'a')], 1);
+    }
+
+    #[test]
+    fn is_arithmetic() {
+        let list: SkipLinkedList<i32> = vec![2, 4, 6, 8].into_iter().collect();
+        assert!(list.is_arithmetic());
+        let list: SkipLinkedList<i32> = vec![1, 2, 4].into_iter().collect();
+        assert!(!list.is_arithmetic());
+        let list: SkipLinkedList<i32> = vec![3, 0, -3].into_iter().collect();
+        assert!(list.is_arithmetic());
+        assert!(SkipLinkedList::<i32>::new().is_arithmetic());
+        assert!(vec![7].into_iter().collect::<SkipLinkedList<i32>>().is_arithmetic());
+
+        let list: SkipLinkedList<u8> = vec![200, 150, 100, 50, 0].into_iter().collect();
+        assert!(list.is_arithmetic());
+        let list: SkipLinkedList<u8> = vec![0, 50, 0].into_iter().collect();
+        assert!(!list.is_arithmetic());
+        let list: SkipLinkedList<u8> = vec![5, 5, 5].into_iter().collect();
+        assert!(list.is_arithmetic());
+        let list: SkipLinkedList<f64> = vec![1.0, f64::NAN].into_iter().collect();
+        assert!(!list.is_arithmetic());
+    }
+
+    #[test]
+    fn is_palindrome() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 1].into_iter().collect();
+        assert!(list.is_palindrome());
+        let list: SkipLinkedList<i32> = vec![1, 2, 2, 1].into_iter().collect();
+        assert!(list.is_palindrome());
+        let list: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        assert!(!list.is_palindrome());
+        let list: SkipLinkedList<i32> = vec![1, 2, 3, 1].into_iter().collect();
+        assert!(!list.is_palindrome());
+        let list: SkipLinkedList<i32> = vec![7].into_iter().collect();
+        assert!(list.is_palindrome());
+        let list: SkipLinkedList<i32> = SkipLinkedList::new();
+        assert!(list.is_palindrome());
+    }
+
+    #[test]
+    fn checksum() {
+        let mut list: SkipLinkedList<String> = (0..100).map(|i| i.to_string()).collect();
+        let mut other = SkipLinkedList::new();
+        for i in 0..100 {
+            other.push_back(i.to_string());
+        }
+        assert_eq!(list.checksum(), other.checksum());
+        list.retune(0.25);
+        assert_eq!(list.checksum(), other.checksum());
+
+        other.remove(50);
+        assert_ne!(list.checksum(), other.checksum());
+        other.insert(50, "49".to_string());
+        assert_ne!(list.checksum(), other.checksum());
+
+        let list: SkipLinkedList<u8> = vec![1, 2].into_iter().collect();
+        let other: SkipLinkedList<u8> = vec![2, 1].into_iter().collect();
+        assert_ne!(list.checksum(), other.checksum());
+        assert_ne!(SkipLinkedList::<u8>::new().checksum(), SkipLinkedList::from_iter(vec![0]).checksum());
+
+        // The same bytes on every platform: the length as a u64, then the elements.
+        let list: SkipLinkedList<(u32, usize)> = vec![(1, 2)].into_iter().collect();
+        let mut hasher = Fnv1a::default();
+        hasher.write(&[1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(list.checksum(), hasher.finish());
+        assert_eq!(SkipLinkedList::<u8>::new().checksum(), 0xa8c7_f832_281a_39c5);
+    }
+
+    #[test]
+    fn eq_iter() {
+        let list = setup_list();
+        assert!(list.eq_iter(vec![10, 20, 30, 100, 1, 2, 3]));
+        assert!(!list.eq_iter(vec![10, 20, 30, 100, 1, 2]));
+        assert!(!list.eq_iter(vec![10, 20, 30, 100, 1, 2, 3, 4]));
+        assert!(!list.eq_iter(vec![10, 20, 31, 100, 1, 2, 3]));
+        assert!(SkipLinkedList::<i32>::new().eq_iter(std::iter::empty()));
+
+        let mut pulled = 0;
+        assert!(!list.eq_iter((0..).inspect(|_| pulled += 1)));
+        assert_eq!(pulled, 1);
+    }
+
+    #[test]
+    fn first_diff() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 3, 4].into_iter().collect();
+        let other: SkipLinkedList<i32> = vec![1, 2, 5, 4].into_iter().collect();
+        assert_eq!(list.first_diff(&other), Some(2));
+        let prefix: SkipLinkedList<i32> = vec![1, 2].into_iter().collect();
+        assert_eq!(list.first_diff(&prefix), Some(2));
+        assert_eq!(prefix.first_diff(&list), Some(2));
+        assert_eq!(list.first_diff(&list.clone()), None);
+        assert_eq!(list.first_diff(&SkipLinkedList::new()), Some(0));
+    }
+
+    #[test]
+    fn diff() {
+        let cases = [("abc", "axc"), ("", "abc"), ("abc", ""), ("kitten", "sitting"), ("abcabba", "cbabac"), ("same", "same")];
+        for (from, to) in cases.iter() {
+            let mut list: SkipLinkedList<char> = from.chars().collect();
+            let other: SkipLinkedList<char> = to.chars().collect();
+            let edits = list.diff(&other);
+            let kept = edits.iter().filter(|edit| **edit == Edit::Keep).count();
+            assert_eq!(edits.len() - kept, from.len() + to.len() - 2 * kept);
+            list.apply_edits(&edits);
+            assert!(list.eq_iter(to.chars()));
+        }
+
+        let list: SkipLinkedList<char> = "abcabba".chars().collect();
+        let other: SkipLinkedList<char> = "cbabac".chars().collect();
+        assert_eq!(list.diff(&other).iter().filter(|edit| **edit == Edit::Keep).count(), 4);
+        assert!(list.diff(&list.clone()).iter().all(|edit| *edit == Edit::Keep));
+
+        for _ in 0..50 {
+            let from: Vec<u8> = (0..thread_rng().gen_range(0, 40)).map(|_| thread_rng().gen_range(0, 4)).collect();
+            let to: Vec<u8> = (0..thread_rng().gen_range(0, 40)).map(|_| thread_rng().gen_range(0, 4)).collect();
+            let mut lcs = vec![vec![0; to.len() + 1]; from.len() + 1];
+            for i in (0..from.len()).rev() {
+                for j in (0..to.len()).rev() {
+                    lcs[i][j] = if from[i] == to[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+                }
+            }
+            let mut list: SkipLinkedList<u8> = from.iter().copied().collect();
+            let edits = list.diff(&to.iter().copied().collect());
+            assert_eq!(edits.iter().filter(|edit| **edit == Edit::Keep).count(), lcs[0][0]);
+            list.apply_edits(&edits);
+            assert!(list.eq_iter(to.iter().copied()));
+        }
+
+        let mut list: SkipLinkedList<u32> = (0..200_000).collect();
+        let mut other = list.clone();
+        other.insert(1000, 7);
+        other.remove(150_000);
+        let edits = list.diff(&other);
+        assert_eq!(edits.len(), 200_001);
+        list.apply_edits(&edits);
+        assert!(list.eq_iter(other.iter().copied()));
+    }
+
+    #[test]
+    fn apply_edits() {
+        for _ in 0..20 {
+            let mut list: SkipLinkedList<i32> = (0..thread_rng().gen_range(0, 60)).map(|_| thread_rng().gen_range(0, 5)).collect();
+            let other: SkipLinkedList<i32> = (0..thread_rng().gen_range(0, 60)).map(|_| thread_rng().gen_range(0, 5)).collect();
+            list.apply_edits(&list.diff(&other));
+            assert!(list.eq_iter(other.iter().copied()));
+            assert!(list.validate_down_pointers());
+        }
+
+        let mut list: SkipLinkedList<i32> = vec![1, 2].into_iter().collect();
+        list.apply_edits(&[Edit::Insert(0, 0), Edit::Keep, Edit::Remove(2), Edit::Insert(2, 3)]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn eq_by_key() {
+        struct Record {
+            id: u32,
+            name: &'static str,
+        }
+        let list: SkipLinkedList<Record> = vec![Record { id: 1, name: "a" }, Record { id: 2, name: "b" }].into_iter().collect();
+        let other: SkipLinkedList<Record> = vec![Record { id: 1, name: "x" }, Record { id: 2, name: "y" }].into_iter().collect();
+        assert!(list.eq_by_key(&other, |record| record.id));
+        assert!(!list.eq_by_key(&other, |record| record.name));
+        let shorter: SkipLinkedList<Record> = vec![Record { id: 1, name: "a" }].into_iter().collect();
+        assert!(!list.eq_by_key(&shorter, |record| record.id));
+        assert!(!shorter.eq_by_key(&list, |record| record.id));
+    }
+
+    #[test]
+    fn index_of_ptr() {
+        let mut list = setup_list();
+        let ptr: *const i32 = list.get(3).unwrap();
+        assert_eq!(list.index_of_ptr(ptr), Some(3));
+        list.remove(0);
+        list.insert(0, 5);
+        list.insert(0, 6);
+        assert_eq!(list.index_of_ptr(ptr), Some(4));
+        assert_eq!(list.get(4), Some(&100));
+        let other = setup_list();
+        assert_eq!(list.index_of_ptr(other.get(3).unwrap()), None);
+        assert_eq!(list.index_of_ptr(&100), None);
+    }
+
+    #[test]
+    fn is_reverse_of() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        let other: SkipLinkedList<i32> = vec![3, 2, 1].into_iter().collect();
+        assert!(list.is_reverse_of(&other));
+        assert!(other.is_reverse_of(&list));
+        assert!(!list.is_reverse_of(&list));
+        let other: SkipLinkedList<i32> = vec![2, 1].into_iter().collect();
+        assert!(!list.is_reverse_of(&other));
+        let other: SkipLinkedList<i32> = vec![4, 3, 2, 1].into_iter().collect();
+        assert!(!list.is_reverse_of(&other));
+        assert!(SkipLinkedList::<i32>::new().is_reverse_of(&SkipLinkedList::new()));
+    }
+
+    #[test]
+    fn to_boxed_slice() {
+        let list = setup_list();
+        let boxed = list.to_boxed_slice();
+        assert_eq!(boxed.len(), list.len());
+        assert_eq!(boxed.to_vec(), list.iter().cloned().collect::<Vec<_>>());
+
+        let list: SkipLinkedList<String> = SkipLinkedList::new();
+        assert!(list.to_boxed_slice().is_empty());
+    }
+
+    #[test]
+    fn coalesce() {
+        let sum_equal = |a: &i32, b: &i32| if a == b { Some(a + b) } else { None };
+        let mut list: SkipLinkedList<i32> = vec![1, 1, 2, 3, 3].into_iter().collect();
+        list.coalesce(sum_equal);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 2, 6]);
+        assert_eq!(list.back(), Some(&6));
+
+        let mut list: SkipLinkedList<i32> = vec![1, 1, 1].into_iter().collect();
+        list.coalesce(sum_equal);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+
+        let vec: Vec<i32> = (0..1000).map(|_| thread_rng().gen_range(0, 3)).collect();
+        let mut list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        list.coalesce(sum_equal);
+        let mut expected: Vec<i32> = Vec::new();
+        let mut mergeable = false;
+        for elem in vec {
+            match expected.last_mut() {
+                Some(last) if mergeable && *last == elem => {
+                    *last += elem;
+                    mergeable = false;
+                },
+                _ => {
+                    expected.push(elem);
+                    mergeable = true;
+                },
+            }
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(list.iter().rev().count(), expected.len());
+        assert!(list.validate_down_pointers());
+
+        let mut list: SkipLinkedList<i32> = SkipLinkedList::new();
+        list.coalesce(sum_equal);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn coalesce_within() {
+        let mut list: SkipLinkedList<f64> = vec![1.0, 1.05, 2.0].into_iter().collect();
+        list.coalesce_within(0.1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1.0, 2.0]);
+
+        let mut list: SkipLinkedList<f64> = vec![1.0, 1.08, 1.16, 1.24, 0.95, 0.5].into_iter().collect();
+        list.coalesce_within(0.1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1.0, 0.95, 0.5]);
+        assert_eq!(list.back(), Some(&0.5));
+
+        let mut list: SkipLinkedList<f64> = vec![1.0, 1.08, 1.16].into_iter().collect();
+        list.coalesce_within(0.1);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1.0]);
+        assert_eq!(list.len(), 1);
+
+        let mut list: SkipLinkedList<i32> = vec![3, 3, 4, 6, 6].into_iter().collect();
+        list.coalesce_within(0.0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 4, 6]);
+        assert!(list.validate_down_pointers());
+    }
+
+    #[test]
+    fn unique() {
+        let mut list: SkipLinkedList<i32> = vec![1, 2, 1, 3, 2, 4].into_iter().collect();
+        list.unique();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.back(), Some(&4));
+
+        let vec: Vec<i32> = (0..1000).map(|_| thread_rng().gen_range(0, 50)).collect();
+        let mut list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        list.unique();
+        let mut seen = HashSet::new();
+        let expected: Vec<i32> = vec.into_iter().filter(|elem| seen.insert(*elem)).collect();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(list.iter().rev().count(), expected.len());
+        assert!(list.validate_down_pointers());
+        for (i, elem) in expected.iter().enumerate() {
+            assert_eq!(list.get(i), Some(elem));
+        }
+
+        let mut list = SkipLinkedList::<i32>::new();
+        list.unique();
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn stable_partition() {
+        let mut list: SkipLinkedList<i32> = vec![1, 2, 3, 4].into_iter().collect();
+        assert_eq!(list.stable_partition(|x| x % 2 == 0), 2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 4, 1, 3]);
+
+        let vec: Vec<i32> = (0..500).map(|_| thread_rng().gen_range(0, 100)).collect();
+        let mut list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        let split = list.stable_partition(|x| *x < 30);
+        let (mut expected, rest): (Vec<i32>, Vec<i32>) = vec.into_iter().partition(|x| *x < 30);
+        assert_eq!(split, expected.len());
+        expected.extend(rest);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(list.back(), expected.last());
+        assert!(list.validate_down_pointers());
+        assert!(list.iter_matches_get());
+
+        assert_eq!(list.stable_partition(|_| false), 0);
+        assert_eq!(list.stable_partition(|_| true), 500);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn stable_partition_keeps_config() {
+        let mut list = SkipLinkedList::with_seed(4);
+        let mut other = SkipLinkedList::with_seed(4);
+        list.set_soft_cap(Some(10));
+        list.extend(0..10);
+        other.extend(0..10);
+        assert_eq!(list.stable_partition(|x| x % 3 == 0), 4);
+        other.stable_partition(|x| x % 3 == 0);
+        assert!(list.eq_iter(vec![0, 3, 6, 9, 1, 2, 4, 5, 7, 8]));
+        assert_eq!(list.try_push_back(10), Err(10));
+        assert_eq!(list.rng.borrow_mut().gen::<u64>(), other.rng.borrow_mut().gen::<u64>());
+
+        let mut seeded = SkipLinkedList::with_seed(4);
+        let mut config = list.clone_config();
+        seeded.extend(0..100);
+        config.extend(0..100);
+        assert_eq!(seeded.render(), config.render());
+    }
+
+    #[test]
+    fn take_matching() {
+        let mut list: SkipLinkedList<i32> = (0..20).collect();
+        let evens = list.take_matching(|x| x % 2 == 0);
+        assert_eq!(evens.iter().copied().collect::<Vec<_>>(), (0..20).step_by(2).collect::<Vec<_>>());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), (1..20).step_by(2).collect::<Vec<_>>());
+        assert_eq!(list.back(), Some(&19));
+        assert_eq!(evens.back(), Some(&18));
+
+        let none = list.take_matching(|x| *x > 100);
+        assert_eq!(none.len(), 0);
+        assert_eq!(list.len(), 10);
+        let all = list.take_matching(|_| true);
+        assert_eq!(all.len(), 10);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn take_matching_random() {
+        for _ in 0..20 {
+            let mut vec: Vec<i32> = (0..1000).map(|_| thread_rng().gen_range(0, 100)).collect();
+            let mut list: SkipLinkedList<i32> = SkipLinkedList::new();
+            for elem in vec.iter() {
+                list.push_back(*elem);
+            }
+            let threshold = thread_rng().gen_range(0, 100);
+            let taken = list.take_matching(|x| *x < threshold);
+            let expected_taken: Vec<i32> = vec.iter().copied().filter(|x| *x < threshold).collect();
+            vec.retain(|x| *x >= threshold);
+
+            assert_eq!(taken.iter().copied().collect::<Vec<_>>(), expected_taken);
+            assert_eq!(list.len(), vec.len());
+            for (i, elem) in vec.iter().enumerate() {
+                assert_eq!(list.get(i), Some(elem));
+            }
+            assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec.iter().rev().collect::<Vec<_>>());
+            for _ in 0..200 {
+                let idx = thread_rng().gen_range(0, vec.len() + 1);
+                list.insert(idx, -1);
+                vec.insert(idx, -1);
+                let idx = thread_rng().gen_range(0, vec.len());
+                assert_eq!(list.remove(idx), vec.remove(idx));
+            }
+            assert_eq!(list.iter().collect::<Vec<_>>(), vec.iter().collect::<Vec<_>>());
+            assert_eq!(list.back(), vec.last());
+        }
+    }
+
+    #[test]
+    fn validate_down_pointers() {
+        let mut list = SkipLinkedList::new();
+        let mut size = 0;
+        assert!(list.validate_down_pointers());
+        for _ in 0..50 {
+            for _ in 0..100 {
+                if size == 0 || thread_rng().gen_bool(0.6) {
+                    let idx = thread_rng().gen_range(0, size + 1);
+                    list.insert(idx, thread_rng().gen_range(0, 100));
+                    size += 1;
+                } else {
+                    list.remove(thread_rng().gen_range(0, size));
+                    size -= 1;
+                }
+            }
+            assert!(list.validate_down_pointers());
+            match thread_rng().gen_range(0, 4) {
+                0 => {
+                    let mut tail = list.split_off(thread_rng().gen_range(0, size + 1));
+                    assert!(list.validate_down_pointers());
+                    assert!(tail.validate_down_pointers());
+                    tail.extend(0..tail.len() as i32 + 1);
+                    assert!(tail.validate_down_pointers());
+                    list.extend(tail);
+                },
+                1 => {
+                    let threshold = thread_rng().gen_range(0, 100);
+                    list.take_matching(|x| *x < threshold);
+                },
+                2 => list.retune(thread_rng().gen_range(0.0, 0.9)),
+                _ => list.extend(0..10),
+            }
+            size = list.len();
+            assert!(list.validate_down_pointers());
+        }
+    }
+
+    #[test]
+    fn insert_top_k() {
+        let mut list = SkipLinkedList::new();
+        let mut seen = Vec::new();
+        for _ in 0..500 {
+            let x: i32 = thread_rng().gen_range(0, 1000);
+            list.insert_top_k(x, 3);
+            seen.push(x);
+            seen.sort();
+            let start = seen.len().saturating_sub(3);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), seen[start..].to_vec());
+        }
+        list.insert_top_k(5000, 0);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn sorted_search() {
+        let list: SkipLinkedList<i32> = vec![1, 3, 3, 3, 5, 8].into_iter().collect();
+        assert_eq!(list.lower_bound(&3), 1);
+        assert_eq!(list.upper_bound(&3), 4);
+        assert_eq!(list.lower_bound(&0), 0);
+        assert_eq!(list.upper_bound(&9), 6);
+        assert_eq!(list.binary_search(&5), Ok(4));
+        assert_eq!(list.binary_search(&6), Err(5));
+        assert_eq!(list.binary_search(&0), Err(0));
+        assert!(list.binary_search(&3).is_ok());
+
+        let mut vec: Vec<i32> = (0..1000).map(|_| thread_rng().gen_range(0, 500)).collect();
+        vec.sort();
+        let list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        for x in 0..500 {
+            assert_eq!(list.lower_bound(&x), vec.partition_point(|elem| *elem < x));
+            assert_eq!(list.upper_bound(&x), vec.partition_point(|elem| *elem <= x));
+        }
+    }
+
+    #[test]
+    fn count_sorted() {
+        let list: SkipLinkedList<i32> = vec![1, 3, 3, 3, 5, 8, 8].into_iter().collect();
+        assert_eq!(list.count_sorted(&3), 3);
+        assert_eq!(list.count_sorted(&8), 2);
+        assert_eq!(list.count_sorted(&1), 1);
+        assert_eq!(list.count_sorted(&4), 0);
+        assert_eq!(list.count_sorted(&0), 0);
+        assert_eq!(list.count_sorted(&9), 0);
+        assert_eq!(SkipLinkedList::<i32>::new().count_sorted(&0), 0);
+    }
+
+    #[test]
+    fn nearest() {
+        let list: SkipLinkedList<i32> = vec![-4, 1, 5, 9, 9, 20].into_iter().collect();
+        assert_eq!(list.nearest(&6), Some(&5));
+        assert_eq!(list.nearest(&8), Some(&9));
+        assert_eq!(list.nearest(&3), Some(&1));
+        assert_eq!(list.nearest(&9), Some(&9));
+        assert_eq!(list.nearest(&-100), Some(&-4));
+        assert_eq!(list.nearest(&100), Some(&20));
+        assert_eq!(SkipLinkedList::<i32>::new().nearest(&0), None);
+
+        let vec: Vec<i32> = (0..300).map(|i| i * 7).collect();
+        let list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        for x in -10..2200 {
+            let expected = vec.iter().min_by_key(|elem| (**elem - x).abs()).unwrap();
+            assert_eq!(list.nearest(&x), Some(expected));
+        }
+    }
+
+    #[test]
+    fn comparator() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Task {
+            priority: u32,
+            name: &'static str,
+        }
+
+        let mut list = SkipLinkedList::new_with_comparator(|a: &Task, b: &Task| a.name.cmp(b.name));
+        for (priority, name) in [(3, "c"), (1, "d"), (2, "a"), (4, "b")].iter() {
+            list.insert_sorted(Task { priority: *priority, name });
+        }
+        assert_eq!(list.iter().map(|task| task.name).collect::<Vec<_>>(), vec!["a", "b", "c", "d"]);
+        assert_eq!(list.binary_search(&Task { priority: 0, name: "c" }), Ok(2));
+        assert_eq!(list.lower_bound(&Task { priority: 9, name: "bb" }), 2);
+        assert_eq!(list.upper_bound(&Task { priority: 0, name: "d" }), 4);
+
+        let mut tail = list.split_off(2);
+        tail.insert_sorted(Task { priority: 0, name: "e" });
+        assert_eq!(tail.iter().map(|task| task.name).collect::<Vec<_>>(), vec!["c", "d", "e"]);
+
+        struct Point {
+            x: f64,
+        }
+        let by_x = |a: &Point, b: &Point| a.x.partial_cmp(&b.x).unwrap();
+        let mut points = SkipLinkedList::new();
+        for x in [2.5, -1.0, 7.0, 0.0].iter() {
+            points.insert_sorted_by(Point { x: *x }, by_x);
+        }
+        assert_eq!(points.iter().map(|point| point.x).collect::<Vec<_>>(), vec![-1.0, 0.0, 2.5, 7.0]);
+        assert_eq!(points.binary_search_by(|point| point.x.partial_cmp(&2.5).unwrap()), Ok(2));
+        assert_eq!(points.lower_bound_by(|point| point.x.partial_cmp(&1.0).unwrap()), 2);
+        assert_eq!(points.upper_bound_by(|point| point.x.partial_cmp(&0.0).unwrap()), 2);
+
+        let mut points = SkipLinkedList::new_with_comparator(by_x);
+        for x in [2.5, -1.0, 7.0, 0.0, 2.5].iter() {
+            points.insert_sorted_with_comparator(Point { x: *x });
+        }
+        assert_eq!(points.iter().map(|point| point.x).collect::<Vec<_>>(), vec![-1.0, 0.0, 2.5, 2.5, 7.0]);
+        assert_eq!(points.binary_search_with_comparator(&Point { x: 7.0 }), Ok(4));
+        assert_eq!(points.binary_search_with_comparator(&Point { x: 1.0 }), Err(2));
+        assert_eq!(points.lower_bound_with_comparator(&Point { x: 2.5 }), 2);
+        assert_eq!(points.upper_bound_with_comparator(&Point { x: 2.5 }), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sorted_without_comparator() {
+        struct Opaque;
+        let mut list = SkipLinkedList::new();
+        list.insert_sorted_with_comparator(Opaque);
+        list.insert_sorted_with_comparator(Opaque);
+    }
+
+    #[test]
+    fn from_reader() {
+        let list = SkipLinkedList::from_reader(&b"1 2 3\n4 5"[..]).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert!(list.validate_down_pointers());
+
+        let list = SkipLinkedList::from_reader(&b"  \n\n"[..]).unwrap();
+        assert_eq!(list.len(), 0);
+
+        match SkipLinkedList::from_reader(&b"1 two 3"[..]) {
+            Err(err) => assert_eq!(err.kind(), io::ErrorKind::InvalidData),
+            Ok(_) => panic!("parsed a non-integer token"),
+        }
+    }
+
+    #[test]
+    fn write_to() {
+        let list = setup_list();
+        let mut out = Vec::new();
+        list.write_to(&mut out).unwrap();
+        assert_eq!(out, b"10 20 30 100 1 2 3");
+
+        let mut out = Vec::new();
+        list.write_lines_to(&mut out).unwrap();
+        assert_eq!(out, b"10\n20\n30\n100\n1\n2\n3\n");
+
+        let mut out = Vec::new();
+        SkipLinkedList::<i32>::new().write_lines_to(&mut out).unwrap();
+        assert!(out.is_empty());
+
+        let mut out = Vec::new();
+        list.write_to(&mut out).unwrap();
+        let list = SkipLinkedList::from_reader(&out[..]).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30, 100, 1, 2, 3]);
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut list: SkipLinkedList<i32> = (0..100).collect();
+        let snapshot = list.snapshot();
+        list.insert(50, -1);
+        list.remove(0);
+        for elem in list.iter_mut() {
+            *elem *= 2;
+        }
+        let copy = snapshot.clone();
+        std::mem::drop(list);
+        assert_eq!(snapshot.len(), 100);
+        assert_eq!(snapshot.get(50), Some(&50));
+        assert_eq!(copy.iter().copied().collect::<Vec<_>>(), (0..100).collect::<Vec<_>>());
+
+        let snapshot = SkipLinkedList::<i32>::new().snapshot();
+        assert!(snapshot.is_empty());
+        assert_eq!(snapshot.get(0), None);
+    }
+
+    #[test]
+    fn cursor_read() {
+        let list: SkipLinkedList<i32> = (0..1000).map(|_| thread_rng().gen()).collect();
+        let mut cursor = list.cursor_read(0);
+        let mut i = 0;
+        while i < list.len() {
+            assert_eq!(cursor.index(), i);
+            assert_eq!(cursor.current(), list.get(i));
+            if thread_rng().gen_bool(0.5) {
+                cursor.advance();
+                i += 1;
+            } else {
+                i += thread_rng().gen_range(0, 100);
+                cursor.seek(i);
+            }
+        }
+        assert_eq!(cursor.current(), None);
+
+        cursor.seek(3);
+        assert_eq!(cursor.current(), list.get(3));
+        let cursor = list.cursor_read(999);
+        assert_eq!(cursor.current(), list.get(999));
+        let mut cursor = list.cursor_read(1000);
+        assert_eq!(cursor.current(), None);
+        cursor.advance();
+        assert_eq!(cursor.current(), None);
+
+        let list = SkipLinkedList::<i32>::new();
+        assert_eq!(list.cursor_read(0).current(), None);
+    }
+
+    #[test]
+    fn reserve_levels() {
+        let mut list = setup_list();
+        list.reserve_levels(10);
+        assert_eq!(list.height(), 10.max(setup_list().height()));
+        assert_eq!(list.len(), 7);
+        assert!(list.eq_iter(vec![10, 20, 30, 100, 1, 2, 3]));
+        list.reserve_levels(3);
+        assert_eq!(list.height(), 10);
+
+        list.insert(2, 15);
+        list.remove(7);
+        list.push_back(4);
+        assert!(list.validate_down_pointers());
+        assert!(list.eq_iter(vec![10, 20, 15, 30, 100, 1, 2, 4]));
+        for i in 0..list.len() {
+            assert!(list.lookup_cost(i) >= 9);
         }
-        node.insert_at(i, elem)
     }
 
-    fn get(start_node: &Node<T>, start_i: usize) -> Option<&T> {
-        let mut node = start_node;
-        let mut i = start_i;
+    #[test]
+    fn flatten_levels() {
+        let mut list: SkipLinkedList<i32> = (0..2000).collect();
+        let height = list.height();
+        list.flatten_levels(height + 1);
+        assert_eq!(list.height(), height);
 
-        while node.delta() <= i {
-            i -= node.delta();
-            node = node.right().unwrap();
+        let cost = list.lookup_cost(1500);
+        list.flatten_levels(2);
+        assert_eq!(list.height(), 2.min(height));
+        assert!(list.lookup_cost(1500) >= cost);
+        assert!(list.validate_down_pointers());
+        assert!(list.iter_matches_get());
+
+        list.flatten_levels(1);
+        assert_eq!(list.height(), 1);
+        assert_eq!(list.get(1999), Some(&1999));
+        list.insert(1000, -1);
+        list.remove(0);
+        assert!(list.validate_down_pointers());
+        assert!(list.iter_matches_get());
+        assert_eq!(list.get(999), Some(&-1));
+        assert_eq!(list.back(), Some(&1999));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_flatten_levels() {
+        let mut list: SkipLinkedList<i32> = (0..10).collect();
+        list.flatten_levels(0);
+    }
+
+    #[test]
+    fn height_histogram() {
+        let mut list = SkipLinkedList::with_seed(11);
+        list.extend(0..1 << 14);
+        let histogram = list.height_histogram();
+        assert_eq!(histogram.len(), list.height());
+        assert_eq!(histogram.iter().sum::<usize>(), 1 << 14);
+        for h in 0..6 {
+            let ratio = histogram[h + 1] as f64 / histogram[h] as f64;
+            assert!(ratio > 0.4 && ratio < 0.6, "{:?}", histogram);
         }
-        node.get_at(i)
+
+        list.retune(0.0);
+        assert_eq!(list.height_histogram(), vec![1 << 14]);
+        assert_eq!(SkipLinkedList::<i32>::new().height_histogram(), vec![0]);
     }
 
-    fn get_at(&self, i: usize) -> Option<&T> {
-        match self {
-            Node::Sentinel { down: Some(node), .. } => Node::get(node, i),
-            Node::Index { down: raw_node, .. } => Node::get(unsafe { raw_node.as_ref() }, i),
-            Node::Content { elem, .. } if i == 0 => Some(&elem),
-            _ => None,
+    #[test]
+    fn level_spans() {
+        let mut list = SkipLinkedList::with_seed(5);
+        list.extend(0..1000);
+        assert_eq!(list.level_spans(0), Some(vec![1; 1000]));
+        for level in 1..list.height() {
+            let spans = list.level_spans(level).unwrap();
+            assert_eq!(spans.iter().sum::<usize>(), 1000);
+            assert!(spans.len() <= list.level_spans(level - 1).unwrap().len());
         }
+        assert_eq!(list.level_spans(list.height()), None);
+
+        list.reserve_levels(list.height() + 1);
+        assert_eq!(list.level_spans(list.height() - 1), Some(vec![1000]));
+        assert_eq!(SkipLinkedList::<i32>::new().level_spans(0), Some(vec![]));
     }
 
-    fn insert_content_after(&mut self, elem: T) -> Option<WeakLink<T>> {
-        let right = self.right_mut();
-        let mut new_node = Box::new(Node::Content { elem, right: right.take() });
-        let raw_new_node: *mut _ = &mut *new_node;
-        *right = Some(new_node);
-        NonNull::new(raw_new_node)
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::prelude::*;
+
+        let mut list: SkipLinkedList<u64> = (0..100_000).collect();
+        for i in 0..1000 {
+            list.insert(i * 37, i as u64);
+        }
+        assert_eq!(list.par_iter().sum::<u64>(), list.iter().sum::<u64>());
+        assert_eq!(list.par_iter().count(), list.len());
+        assert_eq!(list.par_iter().copied().collect::<Vec<_>>(), list.iter().copied().collect::<Vec<_>>());
+        assert_eq!(SkipLinkedList::<u64>::new().par_iter().count(), 0);
     }
 
-    fn insert_index_after(&mut self, i: usize, next_level_inserted: WeakLink<T>) -> Option<WeakLink<T>> {
-        let delta = self.delta();
-        let right = self.right_mut();
-        let mut new_node = Box::new(Node::Index {
-            right: right.take(),
-            down: next_level_inserted,
-            delta: delta - i,
-        });
-        let raw_new_node: *mut _ = &mut *new_node;
-        *right = Some(new_node);
-        *self.delta_mut().unwrap() = i;
-        NonNull::new(raw_new_node)
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn operation_metrics() {
+        let mut list = SkipLinkedList::new();
+        list.retune(0.0);
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        assert_eq!(list.operation_metrics(), Metrics { total_descents: 5, total_node_visits: 10, op_count: 5 });
+        list.get(3);
+        list.remove(2);
+        list.get(4);
+        assert_eq!(list.operation_metrics(), Metrics { total_descents: 7, total_node_visits: 16, op_count: 8 });
+
+        list.enable_finger_search(true);
+        list.get(0);
+        list.get(3);
+        assert_eq!(list.operation_metrics(), Metrics { total_descents: 8, total_node_visits: 20, op_count: 10 });
+
+        list.begin_bulk();
+        list.insert(2, 9);
+        list.remove(0);
+        assert_eq!(list.operation_metrics(), Metrics { total_descents: 8, total_node_visits: 22, op_count: 12 });
+        list.end_bulk();
+
+        let mut list = SkipLinkedList::with_seed(6);
+        list.extend(0..1000);
+        for _ in 0..200 {
+            let i = thread_rng().gen_range(0, list.len());
+            let before = list.operation_metrics().total_node_visits;
+            let cost = list.lookup_cost(i);
+            list.get(i);
+            assert_eq!(list.operation_metrics().total_node_visits - before, cost);
+            let before = list.operation_metrics().total_node_visits;
+            let cost = list.descent_cost(i);
+            list.remove(i);
+            assert_eq!(list.operation_metrics().total_node_visits - before, cost);
+            let before = list.operation_metrics().total_node_visits;
+            let cost = list.descent_cost(i);
+            list.insert(i, 0);
+            assert_eq!(list.operation_metrics().total_node_visits - before, cost);
+        }
     }
 
-    fn insert_at(&mut self, i: usize, elem: T) -> Option<WeakLink<T>> {
-        match self {
-            Node::Content { .. } | Node:: Sentinel { down: None, .. } => self.insert_content_after(elem),
-            Node::Sentinel { down: Some(node), delta, .. } => {
-                *delta += 1;
-                match (Node::insert(node, i, elem), thread_rng().gen_bool(0.5)) {
-                    (Some(next_level_inserted), true) => self.insert_index_after(i, next_level_inserted),
-                    _ => None,
-                }
-            },
-            Node::Index { down: raw_node, delta, .. } => {
-                *delta += 1;
-                match (Node::insert(unsafe { raw_node.as_mut() }, i, elem), thread_rng().gen_bool(0.5)) {
-                    (Some(next_level_inserted), true) => self.insert_index_after(i, next_level_inserted),
-                    _ => None,
-                }
-            },
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn pop_back_without_descent() {
+        let mut list = SkipLinkedList::with_seed(3);
+        list.extend(0..1000);
+        let metrics = list.operation_metrics();
+        for i in (0..1000).rev() {
+            assert_eq!(list.pop_back(), i);
         }
+        assert_eq!(list.operation_metrics().total_descents, metrics.total_descents);
+        assert!(list.validate_down_pointers());
     }
 
-    fn remove(start_node: &mut Node<T>, i: usize) -> T {
-        let mut i = i;
-        let mut node = start_node;
+    #[test]
+    fn node_counts() {
+        let mut list = SkipLinkedList::with_seed(5);
+        list.extend(0..1 << 14);
+        let counts = list.node_counts();
+        assert_eq!(counts.content, list.len());
+        assert_eq!(counts.sentinel, list.height());
+        let ratio = counts.index as f64 / list.len() as f64;
+        assert!(ratio > 0.9 && ratio < 1.1, "{:?}", counts);
 
-        while node.delta() <= i {
-            i -= node.delta();
-            node = node.right_mut().as_mut().unwrap();
-        }
-        node.remove_after(i)
+        list.retune(0.0);
+        assert_eq!(list.node_counts(), NodeCounts { content: 1 << 14, index: 0, sentinel: 1 });
     }
 
-    fn remove_after(&mut self, i: usize) -> T {
-        match self {
-            Node::Sentinel { down: Some(node), delta, .. } => {
-                let removed = Node::remove(node, i);
-                if *delta == i + 1 {
-                    self.remove_right();
-                } else {
-                    *delta -= 1;
-                };
-                removed
-            },
-            Node::Index { down: raw_node, delta, .. } => {
-                let removed = Node::remove(unsafe { raw_node.as_mut() }, i);
-                if *delta == i + 1 {
-                    self.remove_right();
-                } else {
-                    *delta -= 1;
-                }
-                removed
-            },
-            Node::Sentinel { down: None, .. } => self.remove_right().unwrap(),
-            Node::Content {.. } => self.remove_right().unwrap(),
+    #[test]
+    fn lookup_cost() {
+        let n = 1 << 12;
+        let mut list: SkipLinkedList<usize> = SkipLinkedList::with_seed(7);
+        list.extend(0..n);
+        let costs: Vec<usize> = (0..n).map(|i| list.lookup_cost(i)).collect();
+        assert!(costs.iter().sum::<usize>() / n <= 3 * 12);
+        assert!(costs.iter().all(|cost| *cost <= 6 * 12));
+        assert_eq!(list.lookup_cost(n), 0);
+
+        list.retune(0.0);
+        for i in 0..n {
+            assert_eq!(list.lookup_cost(i), i + 1);
         }
     }
 
-    fn remove_right(&mut self) -> Option<T> {
-        let right = self.right_mut();
-        let mut removed = right.take().unwrap();
-        *right = removed.right_mut().take();
-        self.delta_mut().map (|delta| *delta += removed.delta() - 1);
-        match *removed {
-            Node::Content { elem, .. } => Some(elem),
-            _ => None,
+    #[test]
+    fn worst_case_lookup() {
+        let mut list: SkipLinkedList<usize> = SkipLinkedList::with_seed(9);
+        list.extend(0..2000);
+        let (i, cost) = list.worst_case_lookup();
+        assert_eq!(list.lookup_cost(i), cost);
+        assert!((0..2000).all(|j| list.lookup_cost(j) <= cost));
+        assert!((i + 1..2000).all(|j| list.lookup_cost(j) < cost));
+
+        for _ in 0..20 {
+            let list: SkipLinkedList<i32> = (0..thread_rng().gen_range(1, 300)).collect();
+            let expected = (0..list.len()).map(|i| (i, list.lookup_cost(i))).max_by_key(|&(_, cost)| cost).unwrap();
+            assert_eq!(list.worst_case_lookup(), expected);
         }
+
+        list.retune(0.0);
+        assert_eq!(list.worst_case_lookup(), (1999, 2000));
+        assert_eq!(SkipLinkedList::<i32>::new().worst_case_lookup(), (0, 0));
     }
 
-    fn delta(&self) -> usize {
-        match self {
-            Node::Sentinel { delta, .. } => *delta,
-            Node::Content { .. } => 1,
-            Node::Index { delta, .. } => *delta,
+    #[test]
+    fn path_levels() {
+        let mut list: SkipLinkedList<usize> = SkipLinkedList::with_seed(3);
+        list.extend(0..1000);
+        for i in 0..1000 {
+            let levels = list.path_levels(i);
+            assert!(!levels.is_empty() && levels[0] < list.height());
+            assert!(levels.windows(2).all(|pair| pair[0] > pair[1]));
         }
+        assert_eq!(list.path_levels(1000), vec![]);
+
+        list.retune(0.0);
+        assert_eq!(list.path_levels(999), vec![0]);
     }
 
-    fn delta_mut(&mut self) -> Option<&mut usize> {
-        match self {
-            Node::Sentinel { delta, .. } => Some(delta),
-            Node::Content { .. } => None,
-            Node::Index { delta, .. } => Some(delta),
+    #[test]
+    fn zip() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        let other: SkipLinkedList<char> = vec!['a', 'b'].into_iter().collect();
+        let zipped = list.zip(other);
+        assert_eq!(zipped.iter().copied().collect::<Vec<_>>(), vec![(1, 'a'), (2, 'b')]);
+        assert_eq!(zipped.back(), Some(&(2, 'b')));
+
+        let list: SkipLinkedList<usize> = (0..500).collect();
+        let other: SkipLinkedList<usize> = (0..1000).rev().collect();
+        let zipped = list.zip(other);
+        assert_eq!(zipped.len(), 500);
+        assert!(zipped.validate_down_pointers());
+        for i in 0..500 {
+            assert_eq!(zipped.get(i), Some(&(i, 999 - i)));
         }
     }
 
-    fn drop_after(sentinel: &mut Node<T>) {
-        sentinel.right_mut().take().map(|mut node| {
-            while let Some(next_node) = node.right_mut().take() {
-                node = next_node;
-            }
+    #[test]
+    fn unzip() {
+        let list: SkipLinkedList<(i32, char)> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+        let (left, right) = list.unzip();
+        assert_eq!(left.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(right.iter().copied().collect::<Vec<_>>(), vec!['a', 'b']);
+
+        let list: SkipLinkedList<usize> = (0..300).collect();
+        let other: SkipLinkedList<String> = (0..300).map(|i| i.to_string()).collect();
+        let (left, right) = list.zip(other).unzip();
+        assert!(left.validate_down_pointers() && right.validate_down_pointers());
+        assert_eq!(left.back(), Some(&299));
+        assert_eq!(right.get(150).map(String::as_str), Some("150"));
+
+        let (left, right) = SkipLinkedList::<(i32, i32)>::new().unzip();
+        assert_eq!((left.len(), right.len()), (0, 0));
+    }
+
+    #[test]
+    fn scan_into() {
+        let list: SkipLinkedList<i32> = vec![3, 1, 4, 1, 5].into_iter().collect();
+        let maxima = list.scan_into(i32::MIN, |max, elem| {
+            *max = (*max).max(*elem);
+            *max
         });
-        if let Node::Sentinel { down: Some(next_sentinel), .. } = sentinel {
-            Node::drop_after(next_sentinel);
+        assert_eq!(maxima.iter().copied().collect::<Vec<_>>(), vec![3, 3, 4, 4, 5]);
+
+        let list: SkipLinkedList<u64> = (1..=100).collect();
+        let sums = list.scan_into(0, |sum, elem| {
+            *sum += elem;
+            sum.to_string()
+        });
+        assert_eq!(sums.len(), 100);
+        assert_eq!(sums.back().map(String::as_str), Some("5050"));
+        assert_eq!(list.len(), 100);
+    }
+
+    #[test]
+    fn flatten() {
+        let lists: SkipLinkedList<SkipLinkedList<i32>> = vec![vec![1, 2], vec![], vec![3]]
+            .into_iter()
+            .map(|vec| vec.into_iter().collect())
+            .collect();
+        let list = lists.flatten();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.back(), Some(&3));
+
+        let lists: SkipLinkedList<SkipLinkedList<usize>> = (0..50).map(|i| (i * i..(i + 1) * (i + 1)).collect()).collect();
+        let list = lists.flatten();
+        assert!(list.validate_down_pointers());
+        assert_eq!(list.len(), 2500);
+        for i in 0..2500 {
+            assert_eq!(list.get(i), Some(&i));
         }
+        assert_eq!(SkipLinkedList::<SkipLinkedList<i32>>::new().flatten().len(), 0);
     }
-}
 
-impl<T> Drop for SkipLinkedList<T> {
-    fn drop(&mut self) {
-        Node::drop_after(&mut self.entry);
+    #[test]
+    fn by_frequency() {
+        let list: SkipLinkedList<char> = vec!['a', 'b', 'a', 'c', 'a', 'b'].into_iter().collect();
+        let counts = list.by_frequency();
+        assert_eq!(counts.iter().copied().collect::<Vec<_>>(), vec![('a', 3), ('b', 2), ('c', 1)]);
+
+        let list: SkipLinkedList<i32> = vec![5, 4, 4, 5, 6, 7, 7].into_iter().collect();
+        let counts = list.by_frequency();
+        assert_eq!(counts.iter().copied().collect::<Vec<_>>(), vec![(5, 2), (4, 2), (7, 2), (6, 1)]);
+        assert_eq!(SkipLinkedList::<i32>::new().by_frequency().len(), 0);
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
+    fn run_length_encode() {
+        let list: SkipLinkedList<char> = vec!['a', 'a', 'b', 'c', 'c', 'c'].into_iter().collect();
+        let runs = list.run_length_encode();
+        assert_eq!(runs.iter().copied().collect::<Vec<_>>(), vec![('a', 2), ('b', 1), ('c', 3)]);
+        assert!(runs.validate_down_pointers());
 
-    fn setup_list() -> SkipLinkedList<i32> {
-        let mut list = SkipLinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_front(30);
-        list.push_front(20);
-        list.push_front(10);
-        list.insert(3, 100);
-        list
+        let list: SkipLinkedList<i32> = vec![1, 2, 1, 1].into_iter().collect();
+        assert_eq!(list.run_length_encode().iter().copied().collect::<Vec<_>>(), vec![(1, 1), (2, 1), (1, 2)]);
+        assert_eq!(SkipLinkedList::<i32>::new().run_length_encode().len(), 0);
     }
 
     #[test]
-    fn basics() {
-        let mut list = setup_list();
-        assert_eq!(list.len(), 7);
-        let expected = vec![10, 20, 30, 100, 1, 2, 3];
-        for (i, elem) in expected.iter().enumerate() {
-            assert_eq!(list.get(i), Some(elem));
-        }
-        assert_eq!(list.get(10), None);
-        assert_eq!(list.remove(0), 10);
-        assert_eq!(list.remove(0), 20);
-        assert_eq!(list.remove(4), 3);
-        assert_eq!(list.remove(2), 1);
+    fn run_length_decode() {
+        let list: SkipLinkedList<i32> = (0..500).map(|_| thread_rng().gen_range(0, 3)).collect();
+        let decoded = SkipLinkedList::run_length_decode(list.run_length_encode());
+        assert!(decoded.eq_iter(list.iter().copied()));
+        assert!(decoded.validate_down_pointers());
+
+        let pairs: SkipLinkedList<(i32, usize)> = vec![(1, 0), (2, 3)].into_iter().collect();
+        assert_eq!(SkipLinkedList::run_length_decode(pairs).iter().collect::<Vec<_>>(), vec![&2, &2, &2]);
     }
 
     #[test]
-    fn small_random() {
-        let mut list = SkipLinkedList::new();
-        let mut vec = Vec::new();
+    fn window_reduce() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 3, 4].into_iter().collect();
+        let sums = list.window_reduce(2, |window| window.iter().copied().sum::<i32>());
+        assert_eq!(sums.iter().copied().collect::<Vec<_>>(), vec![3, 5, 7]);
+        let maxima = setup_list().window_reduce(3, |window| **window.iter().max().unwrap());
+        assert_eq!(maxima.iter().copied().collect::<Vec<_>>(), vec![30, 100, 100, 100, 3]);
+        assert_eq!(list.window_reduce(4, |window| window.len()).iter().copied().collect::<Vec<_>>(), vec![4]);
+        assert_eq!(list.window_reduce(5, |window| window.len()).len(), 0);
+    }
 
-        let mut size = 0;
-        for _ in 0..1000 {
-            size += 1;
-            let elem: i32 = thread_rng().gen();
-            let idx: usize = thread_rng().gen_range(0, size);
-            list.insert(idx, elem);
-            vec.insert(idx, elem);
-        }
-        assert_eq!(list.len(), vec.len());
-        for i in 0..1000 {
-            assert_eq!(list.get(i), vec.get(i));
-        }
+    #[test]
+    #[should_panic]
+    fn panic_window_reduce() {
+        setup_list().window_reduce(0, |window| window.len());
     }
 
     #[test]
-    fn iter() {
-        let list = setup_list();
-        let mut iter = list.iter();
-        let expected = vec![10, 20, 30, 100, 1, 2, 3];
-        for elem in expected.iter() {
-            assert_eq!(iter.next(), Some(elem));
-        }
-        assert_eq!(iter.next(), None);
+    fn count_inversions() {
+        let list: SkipLinkedList<i32> = vec![3, 1, 2].into_iter().collect();
+        assert_eq!(list.count_inversions(), 2);
+        let list: SkipLinkedList<i32> = (0..100).collect();
+        assert_eq!(list.count_inversions(), 0);
+        let list: SkipLinkedList<i32> = (0..100).rev().collect();
+        assert_eq!(list.count_inversions(), 100 * 99 / 2);
+        assert_eq!(SkipLinkedList::<i32>::new().count_inversions(), 0);
+
+        let vec: Vec<i32> = (0..300).map(|_| thread_rng().gen_range(0, 50)).collect();
+        let list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        let expected = (0..300).flat_map(|i| (i + 1..300).map(move |j| (i, j))).filter(|&(i, j)| vec[i] > vec[j]).count();
+        assert_eq!(list.count_inversions(), expected);
     }
 
     #[test]
-    fn iter_mut() {
-        let mut list = setup_list();
-        let mut iter_mut = list.iter_mut();
-        while let Some(elem) = iter_mut.next() {
-            *elem += 1;
+    fn sample() {
+        let list: SkipLinkedList<i32> = (0..100).collect();
+        for k in [0, 1, 10, 99, 100, 150].iter() {
+            let sample = list.sample(*k);
+            assert_eq!(sample.len(), (*k).min(100));
+            assert_eq!(sample.iter().collect::<HashSet<_>>().len(), sample.len());
+            assert!(sample.iter().all(|x| (0..100).contains(*x)));
         }
-        let expected = vec![11, 21, 31, 101, 2, 3, 4];
-        let mut iter = list.iter();
-        for elem in expected.iter() {
-            assert_eq!(iter.next(), Some(elem));
+        assert_eq!(list.sample(200), list.iter().collect::<Vec<_>>());
+
+        let mut hits = vec![0; 10];
+        let list: SkipLinkedList<usize> = (0..10).collect();
+        for _ in 0..2000 {
+            for x in list.sample(3) {
+                hits[*x] += 1;
+            }
         }
-        assert_eq!(iter.next(), None);
+        assert!(hits.iter().all(|&count| count > 400 && count < 800), "{:?}", hits);
+
+        let mut list = SkipLinkedList::with_seed(4);
+        list.extend(0..1000);
+        let mut other = SkipLinkedList::with_seed(4);
+        other.extend(0..1000);
+        assert_eq!(list.sample(10), other.sample(10));
     }
 
     #[test]
-    fn into_iter() {
-        let list = setup_list();
-        let expected = vec![10, 20, 30, 100, 1, 2, 3];
-        let mut into_iter = list.into_iter();
-
-        for elem in expected {
-            assert_eq!(into_iter.next(), Some(elem));
+    fn kth_smallest() {
+        let mut vec: Vec<i32> = (0..200).map(|i| i / 2).collect();
+        vec.shuffle(&mut thread_rng());
+        let list: SkipLinkedList<i32> = vec.iter().copied().collect();
+        for k in 0..200 {
+            assert_eq!(list.kth_smallest(k), Some(&(k as i32 / 2)));
         }
-        assert_eq!(into_iter.next(), None);
+        assert_eq!(list.kth_smallest(200), None);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec);
+        assert_eq!(SkipLinkedList::<i32>::new().kth_smallest(0), None);
     }
 
     #[test]
-    fn drop() {
-        let size = 50000;
+    fn longest_increasing_run() {
+        let list: SkipLinkedList<i32> = vec![1, 2, 1, 2, 3, 1].into_iter().collect();
+        assert_eq!(list.longest_increasing_run(), 2..5);
+
+        let list: SkipLinkedList<i32> = vec![3, 3, 3].into_iter().collect();
+        assert_eq!(list.longest_increasing_run(), 0..1);
+
+        let list: SkipLinkedList<i32> = SkipLinkedList::new();
+        assert_eq!(list.longest_increasing_run(), 0..0);
+    }
+
+    #[test]
+    fn iter_chain() {
+        let a: SkipLinkedList<i32> = vec![1, 2].into_iter().collect();
+        let b: SkipLinkedList<i32> = SkipLinkedList::new();
+        let c: SkipLinkedList<i32> = vec![3, 4, 5].into_iter().collect();
+        let chained: Vec<_> = SkipLinkedList::iter_chain(&[&a, &b, &c]).copied().collect();
+        assert_eq!(chained, vec![1, 2, 3, 4, 5]);
+        assert_eq!(SkipLinkedList::iter_chain(&[&c, &a]).count(), 5);
+        assert_eq!(a.len(), 2);
+    }
+
+    #[test]
+    fn step_by_iter() {
         let mut list = SkipLinkedList::new();
-        for _ in 0..size {
-            list.push_front(1);
+        for i in 0..10 {
+            list.push_back(i);
         }
+        assert_eq!(list.step_by_iter(3).collect::<Vec<_>>(), vec![&0, &3, &6, &9]);
+        assert_eq!(list.step_by_iter(1).count(), 10);
+        assert_eq!(list.step_by_iter(20).collect::<Vec<_>>(), vec![&0]);
     }
 
     #[test]
-    fn pops() {
-        let mut list = SkipLinkedList::new();
-        list.push_front(1);
-        list.push_front(2);
-        assert_eq!(list.pop_front(), 2);
-        assert_eq!(list.pop_front(), 1);
+    fn decimate() {
+        let mut list: SkipLinkedList<i32> = (0..10).collect();
+        list.decimate(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+        assert_eq!(list.back(), Some(&9));
+        list.decimate(1);
+        assert_eq!(list.len(), 4);
 
-        list.push_back(1);
-        list.push_back(2);
-        assert_eq!(list.pop_back(), 2);
-        assert_eq!(list.pop_back(), 1);
+        let mut list: SkipLinkedList<i32> = (0..1000).collect();
+        list.decimate(7);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), (0..1000).step_by(7).collect::<Vec<_>>());
+        assert!(list.validate_down_pointers());
+        assert!(list.iter_matches_get());
     }
 
     #[test]
     #[should_panic]
-    fn panic_pop_front() {
-        let mut list: SkipLinkedList<i32> = SkipLinkedList::new();
-        list.pop_front();
+    fn panic_decimate() {
+        let mut list: SkipLinkedList<i32> = (0..10).collect();
+        list.decimate(0);
     }
 
     #[test]
     #[should_panic]
-    fn panic_pop_back() {
-        let mut list: SkipLinkedList<i32> = SkipLinkedList::new();
-        list.pop_back();
+    fn panic_step_by_iter() {
+        let list: SkipLinkedList<i32> = SkipLinkedList::new();
+        let _ = list.step_by_iter(0);
     }
 
     #[test]
@@ -1,7 +1,6 @@
 use std::io::{self, Write};
 
-mod list;
-use crate::list::SkipLinkedList;
+use skip_linked_list::SkipLinkedList;
 
 fn help() {
     println!("Unknown command, type h for help");
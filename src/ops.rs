@@ -0,0 +1,113 @@
+//! Recorded list operations, for replaying logs against a `SkipLinkedList`.
+
+use rand::Rng;
+use rand::distributions::{Distribution, Standard, WeightedIndex};
+
+/// A single list operation, as applied by [`SkipLinkedList::apply`](crate::SkipLinkedList::apply).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Op<T> {
+    Insert(usize, T),
+    Remove(usize),
+    Get(usize),
+}
+
+/// The outcome of applying an [`Op`].
+#[derive(Debug, PartialEq)]
+pub enum OpResult<'a, T> {
+    Inserted,
+    Got(Option<&'a T>),
+    Removed(T),
+}
+
+/// A step of an edit script, as produced by
+/// [`SkipLinkedList::diff`](crate::SkipLinkedList::diff).
+///
+/// Indices refer to the list as it is when the edit is applied, after all the edits before it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Edit<T> {
+    /// Inserts the value at the index.
+    Insert(usize, T),
+    /// Removes the element at the index.
+    Remove(usize),
+    /// Leaves the next element as it is.
+    Keep,
+}
+
+/// Generates `n` random operations that are valid when applied in order to an empty list.
+///
+/// `weights` gives the relative frequency of inserts, gets and removes. Gets and removes are only
+/// drawn while the list would be non-empty.
+///
+/// `rng` and the `Standard: Distribution<T>` bound on the element type are from `rand` 0.7, the
+/// version this crate depends on, so callers need the same major version of `rand` to call it.
+///
+/// # Examples
+///
+/// ```
+/// use skip_linked_list::{ops, SkipLinkedList};
+///
+/// let ops: Vec<ops::Op<i32>> = ops::generate(&mut rand::thread_rng(), 100, [70, 20, 10]);
+/// let mut list = SkipLinkedList::new();
+/// for op in ops {
+///     list.apply(op);
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the insert weight is 0, since nothing else is valid on an empty list.
+pub fn generate<T, R: Rng>(rng: &mut R, n: usize, weights: [usize; 3]) -> Vec<Op<T>> where Standard: Distribution<T> {
+    if weights[0] == 0 {
+        panic!("insert weight should be positive (weights are {:?})", weights);
+    }
+    let mut ops = Vec::with_capacity(n);
+    let dist = WeightedIndex::new(weights).unwrap();
+    let mut size = 0;
+    while ops.len() < n {
+        match dist.sample(rng) {
+            0 => {
+                ops.push(Op::Insert(rng.gen_range(0, size + 1), rng.gen()));
+                size += 1;
+            },
+            1 if size > 0 => ops.push(Op::Get(rng.gen_range(0, size))),
+            2 if size > 0 => {
+                ops.push(Op::Remove(rng.gen_range(0, size)));
+                size -= 1;
+            },
+            _ => (),
+        }
+    }
+    ops
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::thread_rng;
+
+    #[test]
+    #[should_panic]
+    fn generate_without_inserts() {
+        let _: Vec<Op<u8>> = generate(&mut thread_rng(), 10, [0, 1, 1]);
+    }
+
+    #[test]
+    fn generate_valid() {
+        let ops: Vec<Op<u8>> = generate(&mut thread_rng(), 1000, [1, 1, 1]);
+        assert_eq!(ops.len(), 1000);
+        let mut size = 0;
+        for op in ops {
+            match op {
+                Op::Insert(i, _) => {
+                    assert!(i <= size);
+                    size += 1;
+                },
+                Op::Get(i) => assert!(i < size),
+                Op::Remove(i) => {
+                    assert!(i < size);
+                    size -= 1;
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! A skiplist-backed list that caches the sums of its elements for range queries.
+
+use rand::{thread_rng, Rng};
+use std::iter::FromIterator;
+use std::ops::{Add, Bound, RangeBounds, Sub};
+
+/// # SumList
+///
+/// `SumList` is an indexable skiplist whose links also record the sum of the elements they skip
+/// over. Inserts and removes update those sums on the way down, so `insert`, `remove`, `get` and
+/// `range_sum` all take `O(log n)` time.
+///
+/// The sums need `Add`, `Sub`, `Copy` and `Default` on every operation, which is why they live in
+/// a separate type rather than in the nodes of `SkipLinkedList`.
+///
+/// # Examples
+/// ```
+/// let mut list: skip_linked_list::SumList<_> = (0..10).collect();
+///
+/// assert_eq!(list.range_sum(2..7), 20);
+/// list.insert(3, 100);
+/// assert_eq!(list.range_sum(2..7), 114);
+/// assert_eq!(list.remove(0), 0);
+/// assert_eq!(list.range_sum(..), 145);
+/// ```
+pub struct SumList<T> {
+    size: usize,
+    nodes: Vec<SumNode<T>>,
+    free: Vec<usize>,
+}
+
+/// A node of the list; `nodes[0]` is the head, at position 0, and holds no element.
+struct SumNode<T> {
+    elem: T,
+    links: Vec<SumLink<T>>,
+}
+
+/// A link on one level. `width` is the distance to the next node, or to `len + 1` for the last
+/// link of a level, and `sum` adds up the elements after this node up to and including that one.
+#[derive(Clone, Copy)]
+struct SumLink<T> {
+    next: Option<usize>,
+    width: usize,
+    sum: T,
+}
+
+/// The node before a position on one level, with its own position and the sum of the elements
+/// up to and including it.
+#[derive(Clone, Copy)]
+struct Step<T> {
+    node: usize,
+    pos: usize,
+    sum: T,
+}
+
+impl<T> SumList<T> where T: Add<Output = T> + Sub<Output = T> + Copy + Default {
+    /// Creates a new list.
+    pub fn new() -> Self {
+        let head = SumNode { elem: T::default(), links: vec![SumLink { next: None, width: 1, sum: T::default() }] };
+        Self { size: 0, nodes: vec![head], free: Vec::new() }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the element at index `i`, or `None` if it is out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.size {
+            return None;
+        }
+        let step = self.seek(i + 1).pop().unwrap();
+        Some(&self.nodes[step.node].elem)
+    }
+
+    /// Appends an element to the back of the list.
+    pub fn push_back(&mut self, elem: T) {
+        self.insert(self.size, elem);
+    }
+
+    /// Inserts an element at index `i`, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i > len`.
+    pub fn insert(&mut self, i: usize, elem: T) {
+        if i > self.size {
+            panic!("insert position {} should be <= len (is {})", i, self.size);
+        }
+        let mut height = 1;
+        while thread_rng().gen_bool(0.5) {
+            height += 1;
+        }
+        while self.nodes[0].links.len() < height {
+            let total = self.prefix_sum(self.size);
+            self.nodes[0].links.push(SumLink { next: None, width: self.size + 1, sum: total });
+        }
+
+        let pos = i + 1;
+        let before = self.seek(i);
+        let prefix = before.last().unwrap().sum;
+        let mut links = Vec::with_capacity(height);
+        for (level, step) in before.iter().rev().enumerate() {
+            let link = &mut self.nodes[step.node].links[level];
+            if level < height {
+                // The elements strictly between the node and the new one.
+                let skipped = prefix - step.sum;
+                links.push(SumLink { next: link.next, width: step.pos + link.width + 1 - pos, sum: link.sum - skipped });
+                link.width = pos - step.pos;
+                link.sum = skipped + elem;
+            } else {
+                link.width += 1;
+                link.sum = link.sum + elem;
+            }
+        }
+
+        let new_node = self.alloc(SumNode { elem, links });
+        for (level, step) in before.iter().rev().take(height).enumerate() {
+            self.nodes[step.node].links[level].next = Some(new_node);
+        }
+        self.size += 1;
+    }
+
+    /// Removes the element at index `i` and returns it, shifting all elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= len`.
+    pub fn remove(&mut self, i: usize) -> T {
+        if i >= self.size {
+            panic!("removal index {} should be < len (is {})", i, self.size);
+        }
+        let before = self.seek(i);
+        let removed = self.nodes[before.last().unwrap().node].links[0].next.unwrap();
+        let SumNode { elem, links } = std::mem::replace(&mut self.nodes[removed], SumNode { elem: T::default(), links: Vec::new() });
+        for (level, step) in before.iter().rev().enumerate() {
+            let link = &mut self.nodes[step.node].links[level];
+            match links.get(level) {
+                Some(removed_link) => {
+                    link.next = removed_link.next;
+                    link.width += removed_link.width - 1;
+                    link.sum = link.sum - elem + removed_link.sum;
+                },
+                None => {
+                    link.width -= 1;
+                    link.sum = link.sum - elem;
+                },
+            }
+        }
+        self.free.push(removed);
+        self.size -= 1;
+
+        let head = &mut self.nodes[0];
+        while head.links.len() > 1 && head.links.last().unwrap().next.is_none() {
+            head.links.pop();
+        }
+        elem
+    }
+
+    /// Returns the sum of the elements in the index `range`, in `O(log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list: skip_linked_list::SumList<_> = vec![5, 1, 4, 2].into_iter().collect();
+    /// assert_eq!(list.range_sum(1..3), 5);
+    /// assert_eq!(list.range_sum(..=1), 6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is after its end, or its end is after `len`.
+    pub fn range_sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.size,
+        };
+        if start > end || end > self.size {
+            panic!("range {}..{} should be within len (is {})", start, end, self.size);
+        }
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        let mut next = self.nodes[0].links[0].next;
+        std::iter::from_fn(move || {
+            let node = &self.nodes[next?];
+            next = node.links[0].next;
+            Some(&node.elem)
+        })
+    }
+
+    /// Returns the sum of the first `k` elements.
+    fn prefix_sum(&self, k: usize) -> T {
+        self.seek(k).pop().unwrap().sum
+    }
+
+    /// Descends from the top to position `pos`, returning the last node at or before it on each
+    /// level, from the top level down.
+    fn seek(&self, pos: usize) -> Vec<Step<T>> {
+        let mut steps = Vec::with_capacity(self.nodes[0].links.len());
+        let mut step = Step { node: 0, pos: 0, sum: T::default() };
+        for level in (0..self.nodes[0].links.len()).rev() {
+            loop {
+                let link = &self.nodes[step.node].links[level];
+                match link.next {
+                    Some(next) if step.pos + link.width <= pos => {
+                        step = Step { node: next, pos: step.pos + link.width, sum: step.sum + link.sum };
+                    },
+                    _ => break,
+                }
+            }
+            steps.push(step);
+        }
+        steps
+    }
+
+    /// Stores a node in a free slot, or at the end, and returns its slot.
+    fn alloc(&mut self, node: SumNode<T>) -> usize {
+        match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = node;
+                slot
+            },
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            },
+        }
+    }
+}
+
+impl<T> Default for SumList<T> where T: Add<Output = T> + Sub<Output = T> + Copy + Default {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for SumList<T> where T: Add<Output = T> + Sub<Output = T> + Copy + Default {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for elem in iter {
+            list.push_back(elem);
+        }
+        list
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn range_sum() {
+        let list: SumList<i64> = vec![10, 20, 30, 100, 1, 2, 3].into_iter().collect();
+        assert_eq!(list.range_sum(2..7), 136);
+        assert_eq!(list.range_sum(3..3), 0);
+        assert_eq!(list.range_sum(..=1), 30);
+        assert_eq!(list.range_sum(..), 166);
+
+        let mut list = SumList::new();
+        let mut vec = Vec::new();
+        for _ in 0..2000 {
+            if vec.len() < 7 || thread_rng().gen_bool(0.6) {
+                let i = thread_rng().gen_range(0, vec.len() + 1);
+                let elem: i64 = thread_rng().gen_range(-100, 100);
+                vec.insert(i, elem);
+                list.insert(i, elem);
+            } else {
+                let i = thread_rng().gen_range(0, vec.len());
+                assert_eq!(list.remove(i), vec.remove(i));
+            }
+            if vec.len() >= 7 {
+                assert_eq!(list.range_sum(2..7), vec[2..7].iter().sum::<i64>());
+            }
+            let a = thread_rng().gen_range(0, vec.len() + 1);
+            let b = thread_rng().gen_range(a, vec.len() + 1);
+            assert_eq!(list.range_sum(a..b), vec[a..b].iter().sum::<i64>());
+        }
+        assert_eq!(list.len(), vec.len());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec);
+        assert!((0..vec.len()).all(|i| list.get(i) == Some(&vec[i])));
+        assert_eq!(list.get(vec.len()), None);
+    }
+
+    #[test]
+    fn remove_all() {
+        let mut list: SumList<f64> = (0..100).map(f64::from).collect();
+        for i in (0..100).rev() {
+            assert_eq!(list.remove(0), f64::from(99 - i));
+            assert_eq!(list.range_sum(..), (100 - i..100).map(f64::from).sum::<f64>());
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.nodes[0].links.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_past_end() {
+        let list: SumList<i32> = (0..3).collect();
+        list.range_sum(1..4);
+    }
+}